@@ -0,0 +1,32 @@
+use aad_domain::repositories::RepositoryError;
+
+/// アプリケーション層のエラー。
+#[derive(Debug, thiserror::Error)]
+pub enum ApplicationError {
+    #[error("session not found: {0}")]
+    SessionNotFound(String),
+
+    #[error("invalid state: {0}")]
+    InvalidState(String),
+
+    /// 検出された全ての循環（各要素が1つの循環のノード列）。
+    #[error("cyclic dependency detected: {0:?}")]
+    CyclicDependency(Vec<Vec<String>>),
+
+    #[error("configuration error: {0}")]
+    Config(String),
+
+    #[error("pattern matching timed out after {0}ms (possible ReDoS)")]
+    PatternTimeout(u128),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+pub type Result<T> = std::result::Result<T, ApplicationError>;
@@ -0,0 +1,9 @@
+//! アプリケーション層 — ユースケースとオーケストレーション。
+//!
+//! domain 層のみに依存し、オーケストレーター・ループエンジン・完了検出など
+//! ワークフロー全体を駆動するサービスを提供する。
+
+pub mod error;
+pub mod services;
+
+pub use error::{ApplicationError, Result};
@@ -0,0 +1,59 @@
+//! アトミックなファイル書き込み。
+//!
+//! `fs::write` による直接上書きは、書き込み中にプロセスが落ちると
+//! ファイルが破損して resume 不能になる。一時ファイル（`<path>.tmp`）へ
+//! 書き込んでから `rename` で置き換えることで、中断されても既存ファイルが
+//! 無傷で残ることを保証する。
+
+use std::path::Path;
+
+/// `path` へアトミックに書き込む。
+///
+/// 親ディレクトリが無ければ作成する。一時ファイルは `<path>.tmp`。
+pub fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    let tmp_path = path.with_extension(match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{ext}.tmp"),
+        None => "tmp".to_string(),
+    });
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_content_without_leftover_tmp() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        write_atomic(&path, "v1").unwrap();
+        write_atomic(&path, "v2").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "v2");
+        assert!(!dir.path().join("state.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_interrupted_write_leaves_existing_file_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        write_atomic(&path, "original").unwrap();
+
+        // 書き込み途中で中断された状況を、rename 前の tmp ファイルが
+        // 残っている状態として再現する
+        std::fs::write(dir.path().join("state.json.tmp"), "partial garbage").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "original");
+
+        // 次の書き込みは stale な tmp を上書きして正常に完了する
+        write_atomic(&path, "next").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "next");
+        assert!(!dir.path().join("state.json.tmp").exists());
+    }
+}
@@ -0,0 +1,418 @@
+use crate::error::{ApplicationError, Result};
+use regex::{Regex, RegexSet};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Instant;
+
+/// 名前と説明付きの完了パターン。
+///
+/// 正規表現の羅列だけでは各パターンの意図が分からないため、名前と
+/// 説明を持たせてマッチ報告やドキュメントに使う。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedPattern {
+    pub name: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl NamedPattern {
+    /// 名前なしのパターン文字列から作る（名前はパターンそのもの）。
+    pub fn unnamed(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        Self {
+            name: pattern.clone(),
+            pattern,
+            description: String::new(),
+        }
+    }
+}
+
+/// 完了検出パターンの設定（`config/completion-patterns.json`）。
+///
+/// 後方互換のため、文字列配列形式の旧設定
+/// （`{"patterns": ["完了："]}`）も名前付き形式
+/// （`{"patterns": [{"name": "...", "pattern": "..."}]}`）も読める。
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionPatterns {
+    pub patterns: Vec<NamedPattern>,
+}
+
+impl<'de> serde::Deserialize<'de> for CompletionPatterns {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // 旧形式（文字列）と新形式（オブジェクト）を untagged で受ける
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum PatternEntry {
+            Named(NamedPattern),
+            Plain(String),
+        }
+
+        #[derive(Deserialize)]
+        struct Raw {
+            patterns: Vec<PatternEntry>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(CompletionPatterns {
+            patterns: raw
+                .patterns
+                .into_iter()
+                .map(|entry| match entry {
+                    PatternEntry::Named(named) => named,
+                    PatternEntry::Plain(pattern) => NamedPattern::unnamed(pattern),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl CompletionPatterns {
+    /// 既定の完了パターン。スタイルシステムの完了トークンに対応する。
+    pub fn default_patterns() -> Vec<String> {
+        vec![
+            "完了：".to_string(),
+            "成功しました：".to_string(),
+            r"All tests passed".to_string(),
+            r"✅ .*完了".to_string(),
+        ]
+    }
+}
+
+/// パターンマッチングの最大許容時間（ReDoS 対策）。
+const PATTERN_TIMEOUT_MS: u128 = 10;
+
+/// `detect` の判定結果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionResult {
+    /// 完了パターンにマッチした。
+    Completed,
+    /// 失敗パターンにマッチした（完了と同時なら失敗を優先）。
+    Failed,
+    /// どちらにもマッチしない（実行継続中とみなす）。
+    InProgress,
+}
+
+/// セッション出力から完了を検出するサービス。
+#[derive(Debug, Clone)]
+pub struct CompletionDetector {
+    patterns: RegexSet,
+    /// 失敗を早期検知するためのネガティブマッチパターン。
+    failure_patterns: RegexSet,
+    /// 各パターンの名前（`matched_pattern_names` の報告用）。
+    pattern_names: Vec<String>,
+    /// 個別保持した完了パターン。キャプチャグループの抽出に使う
+    /// （`RegexSet` はキャプチャ非対応のため二段構え）。
+    compiled: Vec<Regex>,
+    /// 元のパターン文字列。マッチ根拠のログ出力に使う。
+    pattern_strings: Vec<String>,
+    /// マッチングの許容時間（ミリ秒）。テストで短縮できるようフィールド化。
+    timeout_ms: u128,
+}
+
+impl CompletionDetector {
+    /// パターン文字列のリストから検出器を構築する（名前はパターンそのもの）。
+    pub fn from_patterns(patterns: &[String]) -> Result<Self> {
+        let named: Vec<NamedPattern> = patterns
+            .iter()
+            .map(|p| NamedPattern::unnamed(p.clone()))
+            .collect();
+        Self::from_named_patterns(&named)
+    }
+
+    /// 名前付きパターンから検出器を構築する。
+    pub fn from_named_patterns(patterns: &[NamedPattern]) -> Result<Self> {
+        let strings: Vec<String> = patterns.iter().map(|p| p.pattern.clone()).collect();
+        let set = RegexSet::new(&strings)
+            .map_err(|e| ApplicationError::Config(format!("invalid pattern: {e}")))?;
+        let compiled = strings
+            .iter()
+            .map(|p| {
+                Regex::new(p)
+                    .map_err(|e| ApplicationError::Config(format!("invalid pattern: {e}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            patterns: set,
+            failure_patterns: RegexSet::empty(),
+            pattern_names: patterns.iter().map(|p| p.name.clone()).collect(),
+            compiled,
+            pattern_strings: strings,
+            timeout_ms: PATTERN_TIMEOUT_MS,
+        })
+    }
+
+    /// 完了パターンと失敗パターンの両方から検出器を構築する。
+    ///
+    /// 失敗パターンは「エラーで失敗した」ことを早期に検知してリトライへ
+    /// 回すために使う。
+    pub fn from_patterns_with_failures(
+        complete: &[String],
+        failure: &[String],
+    ) -> Result<Self> {
+        let mut detector = Self::from_patterns(complete)?;
+        detector.failure_patterns = RegexSet::new(failure)
+            .map_err(|e| ApplicationError::Config(format!("invalid failure pattern: {e}")))?;
+        Ok(detector)
+    }
+
+    /// 完了/失敗/継続中を判定する。
+    ///
+    /// 完了と失敗が同時にマッチした場合は Failed を優先する。
+    pub fn detect(&self, text: &str) -> DetectionResult {
+        if self.failure_patterns.is_match(text) {
+            DetectionResult::Failed
+        } else if self.patterns.is_match(text) {
+            DetectionResult::Completed
+        } else {
+            DetectionResult::InProgress
+        }
+    }
+
+    /// マッチングの許容時間を変更する（主にテスト用）。
+    pub fn with_timeout_ms(mut self, timeout_ms: u128) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// JSON 設定ファイルから検出器を構築する。
+    pub fn from_config(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let config: CompletionPatterns = serde_json::from_str(&content)?;
+        Self::from_named_patterns(&config.patterns)
+    }
+
+    /// 拡張子から JSON/TOML を自動判別して設定を読み込む。
+    ///
+    /// プロジェクトの他の設定（`config.toml` / `styles.toml`）と形式を
+    /// 統一できるよう、TOML では `patterns = [...]` で表現する。
+    pub fn from_config_auto(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let config: CompletionPatterns = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(&content)
+                .map_err(|e| ApplicationError::Config(format!("invalid TOML: {e}")))?,
+            _ => serde_json::from_str(&content)?,
+        };
+        Self::from_named_patterns(&config.patterns)
+    }
+
+    /// 既定パターンの検出器。
+    pub fn with_defaults() -> Result<Self> {
+        Self::from_patterns(&CompletionPatterns::default_patterns())
+    }
+
+    /// マッチした全パターンのインデックスを返す。
+    ///
+    /// 「完了」と判定された根拠（どのパターンに一致したか）をログに
+    /// 残すために使う。一致が無ければ空。
+    pub fn matched_patterns(&self, text: &str) -> Vec<usize> {
+        self.patterns.matches(text).into_iter().collect()
+    }
+
+    /// インデックスから元のパターン文字列を復元する。
+    pub fn pattern_string(&self, index: usize) -> Option<&str> {
+        self.pattern_strings.get(index).map(|s| s.as_str())
+    }
+
+    /// マッチした全パターンの名前を返す。
+    pub fn matched_pattern_names(&self, text: &str) -> Vec<&str> {
+        self.matched_patterns(text)
+            .into_iter()
+            .filter_map(|i| self.pattern_names.get(i).map(|s| s.as_str()))
+            .collect()
+    }
+
+    /// マッチした完了パターンのキャプチャグループを返す。
+    ///
+    /// `RegexSet` で高速にマッチ有無を判定してから、マッチした最初の
+    /// パターンを `Regex` で個別に適用してキャプチャを抽出する。
+    /// 完了メッセージから進捗数値や成果物名を取り出す用途。
+    /// 一致が無ければ `None`。
+    pub fn capture_completion(&self, text: &str) -> Option<Vec<String>> {
+        let index = self.patterns.matches(text).into_iter().next()?;
+        let captures = self.compiled.get(index)?.captures(text)?;
+        Some(
+            captures
+                .iter()
+                .skip(1) // グループ0（全体一致）は除く
+                .map(|m| m.map(|m| m.as_str().to_string()).unwrap_or_default())
+                .collect(),
+        )
+    }
+
+    /// テキストが完了パターンにマッチするかどうか。
+    ///
+    /// タイムアウト超過を `Err` で返すため、信頼できない入力でも
+    /// プロセスを落とさずに組み込める。
+    pub fn try_is_completed(&self, text: &str) -> Result<bool> {
+        let start = Instant::now();
+        let matched = self.patterns.is_match(text);
+        let elapsed = start.elapsed();
+        // マイクロ秒で比較し、timeout_ms=0（テスト用）でも確実に超過と判定する
+        if elapsed.as_micros() > self.timeout_ms * 1000 {
+            return Err(ApplicationError::PatternTimeout(elapsed.as_millis()));
+        }
+        Ok(matched)
+    }
+
+    /// テキストが完了パターンにマッチするかどうか。
+    ///
+    /// # Panics
+    ///
+    /// マッチングが許容時間を超えた場合、ReDoS を疑って panic する。
+    /// panic を避けたい場合は `try_is_completed` を使うこと。
+    pub fn is_completed(&self, text: &str) -> bool {
+        match self.try_is_completed(text) {
+            Ok(matched) => matched,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_default_completion_tokens() {
+        let detector = CompletionDetector::with_defaults().unwrap();
+        assert!(detector.is_completed("完了：タスクを実装しました"));
+        assert!(detector.is_completed("All tests passed (42 tests)"));
+        assert!(!detector.is_completed("実装中です..."));
+    }
+
+    #[test]
+    fn test_from_config_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("patterns.json");
+        std::fs::write(&path, r#"{"patterns": ["DONE"]}"#).unwrap();
+        let detector = CompletionDetector::from_config(&path).unwrap();
+        assert!(detector.is_completed("DONE"));
+        assert!(!detector.is_completed("完了："));
+    }
+
+    #[test]
+    fn test_from_config_auto_reads_both_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("patterns.json");
+        let toml_path = dir.path().join("patterns.toml");
+        std::fs::write(&json_path, r#"{"patterns": ["DONE"]}"#).unwrap();
+        std::fs::write(&toml_path, "patterns = [\"DONE\"]\n").unwrap();
+
+        // JSON/TOML どちらからも同じ検出器が作れる
+        for path in [&json_path, &toml_path] {
+            let detector = CompletionDetector::from_config_auto(path).unwrap();
+            assert!(detector.is_completed("DONE"));
+            assert!(!detector.is_completed("WIP"));
+        }
+    }
+
+    #[test]
+    fn test_named_patterns_load_and_report_names() {
+        let json = r#"{"patterns": [
+            {"name": "style-completion", "pattern": "完了：", "description": "標準スタイルの完了トークン"},
+            {"name": "tests-pass", "pattern": "All tests passed"}
+        ]}"#;
+        let config: CompletionPatterns = serde_json::from_str(json).unwrap();
+        let detector = CompletionDetector::from_named_patterns(&config.patterns).unwrap();
+
+        assert_eq!(
+            detector.matched_pattern_names("完了：All tests passed"),
+            vec!["style-completion", "tests-pass"]
+        );
+    }
+
+    #[test]
+    fn test_legacy_string_array_format_still_loads() {
+        // 旧形式（文字列配列）も untagged で読める
+        let json = r#"{"patterns": ["完了：", "DONE"]}"#;
+        let config: CompletionPatterns = serde_json::from_str(json).unwrap();
+        assert_eq!(config.patterns.len(), 2);
+        assert_eq!(config.patterns[0].name, "完了：");
+
+        let detector = CompletionDetector::from_named_patterns(&config.patterns).unwrap();
+        assert!(detector.is_completed("DONE"));
+    }
+
+    #[test]
+    fn test_detect_distinguishes_completed_failed_in_progress() {
+        let detector = CompletionDetector::from_patterns_with_failures(
+            &["完了：".to_string()],
+            &["エラー：".to_string(), "FAILED".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(detector.detect("完了：実装done"), DetectionResult::Completed);
+        assert_eq!(detector.detect("エラー：ビルド失敗"), DetectionResult::Failed);
+        assert_eq!(detector.detect("作業中..."), DetectionResult::InProgress);
+        // 完了と失敗が同時にマッチしたら Failed 優先
+        assert_eq!(
+            detector.detect("完了：と思ったが FAILED"),
+            DetectionResult::Failed
+        );
+    }
+
+    #[test]
+    fn test_matched_patterns_returns_all_indices() {
+        let detector = CompletionDetector::from_patterns(&[
+            "完了：".to_string(),
+            "done".to_string(),
+            r"タスク.*完了".to_string(),
+        ])
+        .unwrap();
+
+        // 複数パターンが同時に一致した場合は全インデックスが返る
+        let matches = detector.matched_patterns("完了：タスクは完了 done");
+        assert_eq!(matches, vec![0, 1, 2]);
+        assert_eq!(detector.pattern_string(0), Some("完了："));
+        assert_eq!(detector.pattern_string(2), Some(r"タスク.*完了"));
+        assert_eq!(detector.pattern_string(9), None);
+
+        // 一致なしは空
+        assert!(detector.matched_patterns("作業中").is_empty());
+    }
+
+    #[test]
+    fn test_capture_completion_extracts_groups() {
+        let detector = CompletionDetector::from_patterns(&[
+            r"完了：(\d+)件のタスク（(.+)）".to_string(),
+        ])
+        .unwrap();
+
+        let captures = detector
+            .capture_completion("完了：42件のタスク（認証機能）を実装")
+            .unwrap();
+        assert_eq!(captures, vec!["42", "認証機能"]);
+
+        // 一致しなければ None
+        assert!(detector.capture_completion("作業中").is_none());
+    }
+
+    #[test]
+    fn test_try_is_completed_returns_err_on_timeout() {
+        // 許容時間 0ms なら必ず超過する
+        let detector = CompletionDetector::with_defaults()
+            .unwrap()
+            .with_timeout_ms(0);
+        let err = detector.try_is_completed("完了：done");
+        assert!(matches!(err, Err(ApplicationError::PatternTimeout(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern matching timed out")]
+    fn test_is_completed_still_panics_on_timeout() {
+        let detector = CompletionDetector::with_defaults()
+            .unwrap()
+            .with_timeout_ms(0);
+        detector.is_completed("完了：done");
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_config_error() {
+        let err = CompletionDetector::from_patterns(&["(unclosed".to_string()]);
+        assert!(matches!(err, Err(ApplicationError::Config(_))));
+    }
+}
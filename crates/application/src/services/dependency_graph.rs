@@ -0,0 +1,656 @@
+use crate::error::{ApplicationError, Result};
+use aad_domain::value_objects::SpecId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// 所要時間が未指定の spec に使うデフォルト値（`max_task_duration_min` の既定と同じ60分）。
+pub const DEFAULT_SPEC_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// Spec 間の依存関係を管理する有向グラフ。
+///
+/// キーは spec ID、値はその spec が依存する spec ID のリスト。
+/// `add_dependency` は循環を検出した場合、追加をロールバックしてエラーを返す。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    dependencies: HashMap<String, Vec<String>>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 依存関係を持たないノードを登録する。既に存在する場合は何もしない。
+    ///
+    /// 以前は `add_dependency(id, "")` → `remove_dependency(id, "")` という
+    /// 空文字列ダミーノードの回避策が使われていたが、その場合に空文字列
+    /// ノードが `get_parallel_groups` の結果へ混入していた。
+    pub fn add_node(&mut self, spec_id: &str) {
+        self.dependencies.entry(spec_id.to_string()).or_default();
+    }
+
+    /// `from` が `to` に依存することを登録する。
+    /// 循環が生じる場合は追加を取り消して `CyclicDependency` を返す。
+    pub fn add_dependency(&mut self, from: &str, to: &str) -> Result<()> {
+        self.dependencies
+            .entry(from.to_string())
+            .or_default()
+            .push(to.to_string());
+        // 依存先もノードとして登録しておく（グループ計算の走査対象にするため）
+        self.dependencies.entry(to.to_string()).or_default();
+
+        if self.detect_cycle().is_some() {
+            let cycles = self.detect_all_cycles();
+            // Rollback the edge we just added
+            if let Some(deps) = self.dependencies.get_mut(from) {
+                if let Some(pos) = deps.iter().rposition(|d| d == to) {
+                    deps.remove(pos);
+                }
+            }
+            return Err(ApplicationError::CyclicDependency(cycles));
+        }
+        Ok(())
+    }
+
+    /// `from` → `to` の依存エッジを取り除く。ノード自体は残る。
+    pub fn remove_dependency(&mut self, from: &str, to: &str) {
+        if let Some(deps) = self.dependencies.get_mut(from) {
+            deps.retain(|d| d != to);
+        }
+    }
+
+    /// 指定ノードの直接依存先。
+    pub fn get_dependencies(&self, id: &str) -> Vec<String> {
+        self.dependencies.get(id).cloned().unwrap_or_default()
+    }
+
+    /// グラフが保持する全ノード。
+    pub fn nodes(&self) -> Vec<String> {
+        self.dependencies.keys().cloned().collect()
+    }
+
+    /// 内部の依存マップへの参照（状態保存用）。
+    pub fn edges(&self) -> &HashMap<String, Vec<String>> {
+        &self.dependencies
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.dependencies.len()
+    }
+
+    /// 指定 Spec の影響範囲（その Spec と全ての推移的 dependents）だけを
+    /// 含む部分グラフを返す。
+    ///
+    /// `orchestrate` で「変更した Spec の下流だけを再実行する」
+    /// ユースケースに使う。部分グラフのエッジは影響範囲内の
+    /// ノード同士のものだけが残る。
+    pub fn subgraph_affected_by(&self, spec_id: &str) -> DependencyGraph {
+        // 逆向き（依存される側 → 依存する側）に辿って影響ノードを集める
+        let mut affected: HashSet<String> = HashSet::new();
+        let mut queue: Vec<&str> = vec![spec_id];
+        while let Some(current) = queue.pop() {
+            if !affected.insert(current.to_string()) {
+                continue;
+            }
+            for (node, deps) in &self.dependencies {
+                if deps.iter().any(|d| d == current) {
+                    queue.push(node);
+                }
+            }
+        }
+
+        let dependencies = self
+            .dependencies
+            .iter()
+            .filter(|(node, _)| affected.contains(*node))
+            .map(|(node, deps)| {
+                let deps = deps
+                    .iter()
+                    .filter(|d| affected.contains(*d))
+                    .cloned()
+                    .collect();
+                (node.clone(), deps)
+            })
+            .collect();
+
+        DependencyGraph { dependencies }
+    }
+
+    /// Graphviz DOT 形式で出力する。
+    ///
+    /// 各 spec をノード、依存を有向エッジとして出力する。ウェーブごとに
+    /// `rank=same` のサブグラフでグルーピングし、循環がある場合は該当
+    /// エッジを `color=red` で強調する（その場合ウェーブ分割は出さない）。
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph dependencies {\n  rankdir=BT;\n");
+
+        // 循環エッジの集合（循環が無ければ空）
+        let mut cycle_edges: HashSet<(String, String)> = HashSet::new();
+        if let Some(cycle) = self.detect_cycle() {
+            for pair in cycle.windows(2) {
+                // cycle は [a, b, ..., a] の形式。エッジ方向は a → b
+                cycle_edges.insert((pair[0].clone(), pair[1].clone()));
+            }
+        }
+
+        if cycle_edges.is_empty() {
+            if let Ok(groups) = self.get_parallel_groups() {
+                for (i, wave) in groups.iter().enumerate() {
+                    out.push_str(&format!("  subgraph cluster_wave_{i} {{\n    rank=same;\n"));
+                    for node in wave {
+                        out.push_str(&format!("    \"{node}\";\n"));
+                    }
+                    out.push_str("  }\n");
+                }
+            }
+        } else {
+            let mut nodes = self.nodes();
+            nodes.sort();
+            for node in nodes {
+                out.push_str(&format!("  \"{node}\";\n"));
+            }
+        }
+
+        let mut edges: Vec<(&String, &String)> = self
+            .dependencies
+            .iter()
+            .flat_map(|(from, deps)| deps.iter().map(move |to| (from, to)))
+            .collect();
+        edges.sort();
+        for (from, to) in edges {
+            if cycle_edges.contains(&(from.clone(), to.clone())) {
+                out.push_str(&format!("  \"{from}\" -> \"{to}\" [color=red];\n"));
+            } else {
+                out.push_str(&format!("  \"{from}\" -> \"{to}\";\n"));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// クリティカルパス（依存を考慮した最長経路）とその合計時間を返す。
+    ///
+    /// 並列実行したときの最小完了時間の見積もりに使う。`durations` に
+    /// 無い spec は `DEFAULT_SPEC_DURATION` とみなす。循環がある場合は
+    /// エラー。パスは依存先 → 依存元の実行順で返す。
+    pub fn critical_path(
+        &self,
+        durations: &HashMap<SpecId, Duration>,
+    ) -> Result<(Vec<SpecId>, Duration)> {
+        if self.detect_cycle().is_some() {
+            return Err(ApplicationError::CyclicDependency(self.detect_all_cycles()));
+        }
+
+        let duration_of = |node: &str| {
+            durations
+                .get(&SpecId::from(node))
+                .copied()
+                .unwrap_or(DEFAULT_SPEC_DURATION)
+        };
+
+        // cost(n) = duration(n) + max(cost(deps))。メモ化 DFS で計算し、
+        // 最長経路の依存先も記録してパスを復元する
+        fn cost_of<'a>(
+            node: &'a str,
+            graph: &'a DependencyGraph,
+            duration_of: &impl Fn(&str) -> Duration,
+            memo: &mut HashMap<&'a str, (Duration, Option<&'a str>)>,
+        ) -> Duration {
+            if let Some((cost, _)) = memo.get(node) {
+                return *cost;
+            }
+            let mut best: Option<(&'a str, Duration)> = None;
+            if let Some(deps) = graph.dependencies.get(node) {
+                for dep in deps {
+                    let dep_cost = cost_of(dep, graph, duration_of, memo);
+                    if best.is_none_or(|(_, b)| dep_cost > b) {
+                        best = Some((dep, dep_cost));
+                    }
+                }
+            }
+            let cost = duration_of(node) + best.map(|(_, c)| c).unwrap_or_default();
+            memo.insert(node, (cost, best.map(|(d, _)| d)));
+            cost
+        }
+
+        let mut memo: HashMap<&str, (Duration, Option<&str>)> = HashMap::new();
+        let mut end: Option<(&str, Duration)> = None;
+        let mut nodes: Vec<&String> = self.dependencies.keys().collect();
+        nodes.sort();
+        for node in nodes {
+            let cost = cost_of(node, self, &duration_of, &mut memo);
+            if end.is_none_or(|(_, b)| cost > b) {
+                end = Some((node, cost));
+            }
+        }
+
+        let Some((end_node, total)) = end else {
+            return Ok((Vec::new(), Duration::ZERO));
+        };
+
+        // 終端から依存方向へ辿り、実行順（依存先が先）に並べ替える
+        let mut path = Vec::new();
+        let mut current = Some(end_node);
+        while let Some(node) = current {
+            path.push(SpecId::from(node));
+            current = memo.get(node).and_then(|(_, prev)| *prev);
+        }
+        path.reverse();
+        Ok((path, total))
+    }
+
+    /// 全ての循環を列挙する。
+    ///
+    /// Tarjan の強連結成分分解でサイズ2以上の SCC をすべて抽出する。
+    /// 複数の独立した循環があっても一度の実行で全件検出できるため、
+    /// ユーザーが「1つ直して再実行 → 別の循環でまた失敗」を繰り返さずに済む。
+    pub fn detect_all_cycles(&self) -> Vec<Vec<String>> {
+        struct Tarjan<'a> {
+            dependencies: &'a HashMap<String, Vec<String>>,
+            index: usize,
+            indices: HashMap<&'a str, usize>,
+            lowlinks: HashMap<&'a str, usize>,
+            stack: Vec<&'a str>,
+            on_stack: HashSet<&'a str>,
+            sccs: Vec<Vec<String>>,
+        }
+
+        impl<'a> Tarjan<'a> {
+            fn strongconnect(&mut self, node: &'a str) {
+                self.indices.insert(node, self.index);
+                self.lowlinks.insert(node, self.index);
+                self.index += 1;
+                self.stack.push(node);
+                self.on_stack.insert(node);
+
+                if let Some(deps) = self.dependencies.get(node) {
+                    for dep in deps {
+                        if !self.indices.contains_key(dep.as_str()) {
+                            self.strongconnect(dep);
+                            let low = self.lowlinks[dep.as_str()].min(self.lowlinks[node]);
+                            self.lowlinks.insert(node, low);
+                        } else if self.on_stack.contains(dep.as_str()) {
+                            let low = self.indices[dep.as_str()].min(self.lowlinks[node]);
+                            self.lowlinks.insert(node, low);
+                        }
+                    }
+                }
+
+                if self.lowlinks[node] == self.indices[node] {
+                    let mut component = Vec::new();
+                    while let Some(top) = self.stack.pop() {
+                        self.on_stack.remove(top);
+                        component.push(top.to_string());
+                        if top == node {
+                            break;
+                        }
+                    }
+                    if component.len() >= 2 {
+                        component.sort();
+                        self.sccs.push(component);
+                    }
+                }
+            }
+        }
+
+        let mut tarjan = Tarjan {
+            dependencies: &self.dependencies,
+            index: 0,
+            indices: HashMap::new(),
+            lowlinks: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            sccs: Vec::new(),
+        };
+        let mut nodes: Vec<&String> = self.dependencies.keys().collect();
+        nodes.sort();
+        for node in nodes {
+            if !tarjan.indices.contains_key(node.as_str()) {
+                tarjan.strongconnect(node);
+            }
+        }
+        tarjan.sccs.sort();
+        tarjan.sccs
+    }
+
+    /// 循環を検出する。見つかった最初の循環のノード列を返す。
+    pub fn detect_cycle(&self) -> Option<Vec<String>> {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut in_stack: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = Vec::new();
+
+        for node in self.dependencies.keys() {
+            if !visited.contains(node.as_str()) {
+                if let Some(cycle) =
+                    self.dfs_cycle(node, &mut visited, &mut in_stack, &mut stack)
+                {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+
+    fn dfs_cycle<'a>(
+        &'a self,
+        node: &'a str,
+        visited: &mut HashSet<&'a str>,
+        in_stack: &mut HashSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        visited.insert(node);
+        in_stack.insert(node);
+        stack.push(node);
+
+        if let Some(deps) = self.dependencies.get(node) {
+            for dep in deps {
+                if in_stack.contains(dep.as_str()) {
+                    // Found a cycle: slice the stack from the first occurrence
+                    let start = stack.iter().position(|n| *n == dep.as_str()).unwrap_or(0);
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|s| s.to_string()).collect();
+                    cycle.push(dep.clone());
+                    return Some(cycle);
+                }
+                if !visited.contains(dep.as_str()) {
+                    if let Some(cycle) = self.dfs_cycle(dep, visited, in_stack, stack) {
+                        return Some(cycle);
+                    }
+                }
+            }
+        }
+
+        stack.pop();
+        in_stack.remove(node);
+        None
+    }
+
+    /// 依存先が先に来るトポロジカル順序を返す。
+    pub fn topological_sort(&self) -> Result<Vec<String>> {
+        let groups = self.get_parallel_groups()?;
+        Ok(groups.into_iter().flatten().collect())
+    }
+
+    /// 並列実行可能なウェーブに分割する。
+    /// ウェーブ N のノードはウェーブ 0..N の完了後に実行できる。
+    ///
+    /// 各ノードの「最長依存深さ」を一度のトポロジカル走査（メモ化 DFS）で
+    /// 計算し、同じ深さのノードを同じウェーブにまとめる O(N+E) の
+    /// アルゴリズム。以前の全ノード反復スキャン（最悪 O(N²)）と同じ
+    /// ウェーブ分割を返す。
+    pub fn get_parallel_groups(&self) -> Result<Vec<Vec<String>>> {
+        if self.detect_cycle().is_some() {
+            return Err(ApplicationError::CyclicDependency(self.detect_all_cycles()));
+        }
+
+        fn depth_of<'a>(
+            node: &'a str,
+            dependencies: &'a HashMap<String, Vec<String>>,
+            memo: &mut HashMap<&'a str, usize>,
+        ) -> usize {
+            if let Some(&d) = memo.get(node) {
+                return d;
+            }
+            let d = dependencies
+                .get(node)
+                .map(|deps| {
+                    deps.iter()
+                        .map(|dep| depth_of(dep, dependencies, memo) + 1)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .unwrap_or(0);
+            memo.insert(node, d);
+            d
+        }
+
+        let mut memo: HashMap<&str, usize> = HashMap::with_capacity(self.dependencies.len());
+        let mut groups: Vec<Vec<String>> = Vec::new();
+        for node in self.dependencies.keys() {
+            let depth = depth_of(node, &self.dependencies, &mut memo);
+            if groups.len() <= depth {
+                groups.resize_with(depth + 1, Vec::new);
+            }
+            groups[depth].push(node.clone());
+        }
+        for wave in &mut groups {
+            wave.sort();
+        }
+        Ok(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_node_registers_without_dependencies() {
+        let mut graph = DependencyGraph::new();
+        graph.add_node("SPEC-001");
+        graph.add_node("SPEC-001"); // 冪等
+
+        assert_eq!(graph.nodes(), vec!["SPEC-001"]);
+        assert!(graph.get_dependencies("SPEC-001").is_empty());
+        // 空文字列キーが混入しない
+        assert!(!graph.edges().contains_key(""));
+        assert_eq!(graph.get_parallel_groups().unwrap(), vec![vec!["SPEC-001"]]);
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        assert_eq!(graph.get_dependencies("SPEC-002"), vec!["SPEC-001"]);
+    }
+
+    #[test]
+    fn test_cycle_detected_and_rolled_back() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        let err = graph.add_dependency("SPEC-001", "SPEC-002");
+        assert!(matches!(err, Err(ApplicationError::CyclicDependency(_))));
+        // ロールバックされているので以降の操作は成功する
+        assert!(graph.get_parallel_groups().is_ok());
+    }
+
+    #[test]
+    fn test_parallel_groups() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        graph.add_dependency("SPEC-003", "SPEC-001").unwrap();
+        graph.add_dependency("SPEC-004", "SPEC-002").unwrap();
+
+        let groups = graph.get_parallel_groups().unwrap();
+        assert_eq!(groups[0], vec!["SPEC-001"]);
+        assert_eq!(groups[1], vec!["SPEC-002", "SPEC-003"]);
+        assert_eq!(groups[2], vec!["SPEC-004"]);
+    }
+
+    #[test]
+    fn test_parallel_groups_scale_to_large_graphs() {
+        // 1000 ノードの直列チェーン + 分岐。旧 O(N²) 実装では顕著に
+        // 遅かったサイズでも現実的な時間で完了することを確認する。
+        let mut graph = DependencyGraph::new();
+        for i in 1..1000 {
+            graph
+                .add_dependency(&format!("SPEC-{i:04}"), &format!("SPEC-{:04}", i - 1))
+                .unwrap();
+        }
+
+        let groups = graph.get_parallel_groups().unwrap();
+        assert_eq!(groups.len(), 1000);
+        assert_eq!(groups[0], vec!["SPEC-0000"]);
+        assert_eq!(groups[999], vec!["SPEC-0999"]);
+    }
+
+    #[test]
+    fn test_subgraph_affected_by_contains_transitive_dependents() {
+        let mut graph = DependencyGraph::new();
+        // SPEC-002 → SPEC-001, SPEC-003 → SPEC-002, SPEC-004 は無関係
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        graph.add_dependency("SPEC-003", "SPEC-002").unwrap();
+        graph.add_dependency("SPEC-004", "SPEC-005").unwrap();
+
+        let sub = graph.subgraph_affected_by("SPEC-001");
+        let mut nodes = sub.nodes();
+        nodes.sort();
+        assert_eq!(nodes, vec!["SPEC-001", "SPEC-002", "SPEC-003"]);
+        // エッジも影響範囲内のものだけ残る
+        assert_eq!(sub.get_dependencies("SPEC-003"), vec!["SPEC-002"]);
+    }
+
+    #[test]
+    fn test_subgraph_of_leaf_is_single_node() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+
+        let sub = graph.subgraph_affected_by("SPEC-002");
+        assert_eq!(sub.nodes(), vec!["SPEC-002"]);
+        assert!(sub.get_dependencies("SPEC-002").is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_serial_chain() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        graph.add_dependency("SPEC-003", "SPEC-002").unwrap();
+
+        let mut durations = HashMap::new();
+        durations.insert(SpecId::from("SPEC-001"), Duration::from_secs(10));
+        durations.insert(SpecId::from("SPEC-002"), Duration::from_secs(20));
+        durations.insert(SpecId::from("SPEC-003"), Duration::from_secs(30));
+
+        let (path, total) = graph.critical_path(&durations).unwrap();
+        assert_eq!(
+            path,
+            vec![
+                SpecId::from("SPEC-001"),
+                SpecId::from("SPEC-002"),
+                SpecId::from("SPEC-003")
+            ]
+        );
+        assert_eq!(total, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_critical_path_picks_longest_branch() {
+        // SPEC-003 は SPEC-001(短) と SPEC-002(長) に依存する分岐
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-003", "SPEC-001").unwrap();
+        graph.add_dependency("SPEC-003", "SPEC-002").unwrap();
+
+        let mut durations = HashMap::new();
+        durations.insert(SpecId::from("SPEC-001"), Duration::from_secs(5));
+        durations.insert(SpecId::from("SPEC-002"), Duration::from_secs(50));
+        durations.insert(SpecId::from("SPEC-003"), Duration::from_secs(10));
+
+        let (path, total) = graph.critical_path(&durations).unwrap();
+        assert_eq!(
+            path,
+            vec![SpecId::from("SPEC-002"), SpecId::from("SPEC-003")]
+        );
+        assert_eq!(total, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_critical_path_uses_default_for_unspecified() {
+        let mut graph = DependencyGraph::new();
+        graph.add_node("SPEC-001");
+        let (path, total) = graph.critical_path(&HashMap::new()).unwrap();
+        assert_eq!(path, vec![SpecId::from("SPEC-001")]);
+        assert_eq!(total, DEFAULT_SPEC_DURATION);
+    }
+
+    #[test]
+    fn test_critical_path_rejects_cycles() {
+        let graph: DependencyGraph = serde_json::from_str(
+            r#"{"dependencies":{"A":["B"],"B":["A"]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            graph.critical_path(&HashMap::new()),
+            Err(ApplicationError::CyclicDependency(_))
+        ));
+    }
+
+    #[test]
+    fn test_detect_all_cycles_finds_independent_cycles() {
+        // 3つの独立した循環を持つグラフ（デシリアライズで構築）
+        let graph: DependencyGraph = serde_json::from_str(
+            r#"{"dependencies":{
+                "A1":["A2"],"A2":["A1"],
+                "B1":["B2"],"B2":["B3"],"B3":["B1"],
+                "C1":["C2"],"C2":["C1"],
+                "D":[]
+            }}"#,
+        )
+        .unwrap();
+
+        let cycles = graph.detect_all_cycles();
+        assert_eq!(cycles.len(), 3);
+        assert!(cycles.contains(&vec!["A1".to_string(), "A2".to_string()]));
+        assert!(cycles.contains(&vec![
+            "B1".to_string(),
+            "B2".to_string(),
+            "B3".to_string()
+        ]));
+        assert!(cycles.contains(&vec!["C1".to_string(), "C2".to_string()]));
+    }
+
+    #[test]
+    fn test_detect_all_cycles_empty_for_acyclic_graph() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        assert!(graph.detect_all_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_to_dot_outputs_valid_structure_with_waves() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+        graph.add_node("SPEC-003");
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph dependencies {"));
+        assert!(dot.trim_end().ends_with('}'));
+        // 括弧が釣り合っている（dot コマンドでパース可能な構造）
+        assert_eq!(
+            dot.matches('{').count(),
+            dot.matches('}').count()
+        );
+        assert!(dot.contains("\"SPEC-002\" -> \"SPEC-001\";"));
+        assert!(dot.contains("rank=same"));
+    }
+
+    #[test]
+    fn test_to_dot_highlights_cycle_edges() {
+        // add_dependency は循環をロールバックするため、循環グラフは
+        // デシリアライズで作る（壊れた state の可視化を想定）
+        let graph: DependencyGraph = serde_json::from_str(
+            r#"{"dependencies":{"SPEC-001":["SPEC-002"],"SPEC-002":["SPEC-001"]}}"#,
+        )
+        .unwrap();
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("[color=red]"));
+    }
+
+    #[test]
+    fn test_topological_sort_respects_dependencies() {
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("SPEC-003", "SPEC-002").unwrap();
+        graph.add_dependency("SPEC-002", "SPEC-001").unwrap();
+
+        let order = graph.topological_sort().unwrap();
+        let pos = |id: &str| order.iter().position(|n| n == id).unwrap();
+        assert!(pos("SPEC-001") < pos("SPEC-002"));
+        assert!(pos("SPEC-002") < pos("SPEC-003"));
+    }
+}
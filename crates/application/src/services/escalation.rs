@@ -0,0 +1,377 @@
+use crate::error::Result;
+use aad_domain::value_objects::{SessionId, SpecId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// エスカレーションの深刻度。CLAUDE.md のエスカレーションルールに対応する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EscalationLevel {
+    /// 🟢 情報（ログ記録のみ）
+    Info,
+    /// 🟡 警告（作業継続）
+    Warning,
+    /// 🔴 即時（作業停止）
+    Critical,
+}
+
+impl EscalationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EscalationLevel::Info => "info",
+            EscalationLevel::Warning => "warning",
+            EscalationLevel::Critical => "critical",
+        }
+    }
+}
+
+impl std::str::FromStr for EscalationLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(EscalationLevel::Info),
+            "warning" => Ok(EscalationLevel::Warning),
+            "critical" => Ok(EscalationLevel::Critical),
+            other => Err(format!("unknown escalation level: {other}")),
+        }
+    }
+}
+
+/// エスカレーションの記録。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Escalation {
+    pub session_id: SessionId,
+    pub spec_id: SpecId,
+    pub level: EscalationLevel,
+    pub reason: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl Escalation {
+    pub fn new(
+        session_id: SessionId,
+        spec_id: SpecId,
+        level: EscalationLevel,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            session_id,
+            spec_id,
+            level,
+            reason: reason.into(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+/// EscalationHandler が参照する通知設定（config.toml の [notifications]）。
+#[derive(Debug, Clone)]
+pub struct NotificationSettings {
+    pub enabled: bool,
+    pub webhook_url: Option<String>,
+    /// 通知する最小レベル。
+    pub min_level: EscalationLevel,
+}
+
+/// バッチモードの内部状態。
+#[derive(Debug)]
+struct BatchState {
+    window: Duration,
+    buffer: Mutex<Vec<Escalation>>,
+    /// 現在のウィンドウの開始時刻（バッファが空なら None）。
+    window_start: Mutex<Option<tokio::time::Instant>>,
+}
+
+/// エスカレーションを `.aad/escalations/` に記録するハンドラ。
+#[derive(Debug, Clone)]
+pub struct EscalationHandler {
+    dir: PathBuf,
+    notifications: Option<NotificationSettings>,
+    /// バッチモード。None なら全件即時に記録する。
+    batch: Option<Arc<BatchState>>,
+}
+
+impl EscalationHandler {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            notifications: None,
+            batch: None,
+        }
+    }
+
+    /// バッチモードを有効にする。
+    ///
+    /// Critical は従来通り即時記録し、それ以外はウィンドウ内で
+    /// バッファして1つのサマリにまとめる。大量のセッションが同時期に
+    /// 失敗したときの通知ノイズを抑える。
+    pub fn with_batching(mut self, window_secs: u64) -> Self {
+        self.batch = Some(Arc::new(BatchState {
+            window: Duration::from_secs(window_secs),
+            buffer: Mutex::new(Vec::new()),
+            window_start: Mutex::new(None),
+        }));
+        self
+    }
+
+    /// 通知設定を与える。未設定（または enabled=false）なら通知しない。
+    pub fn with_notifications(mut self, settings: NotificationSettings) -> Self {
+        self.notifications = Some(settings);
+        self
+    }
+
+    /// エスカレーションをマークダウンファイルとして書き出す。
+    ///
+    /// 監視ループから呼ばれるため、ランタイムをブロックしないよう
+    /// `tokio::fs` で非同期に書き込む。
+    pub async fn handle(&self, escalation: &Escalation) -> Result<()> {
+        // バッチモードでは Critical 以外をウィンドウ内でバッファする
+        if let Some(batch) = &self.batch {
+            if escalation.level != EscalationLevel::Critical {
+                let now = tokio::time::Instant::now();
+                batch.buffer.lock().await.push(escalation.clone());
+                let mut start = batch.window_start.lock().await;
+                match *start {
+                    None => {
+                        *start = Some(now);
+                        return Ok(());
+                    }
+                    Some(opened) if now.duration_since(opened) < batch.window => {
+                        return Ok(());
+                    }
+                    Some(_) => {
+                        *start = None;
+                        drop(start);
+                        return self.flush_batch().await;
+                    }
+                }
+            }
+        }
+        self.write_record(escalation).await?;
+        self.notify(escalation).await?;
+        Ok(())
+    }
+
+    /// バッファ中のエスカレーションを1つのサマリとして書き出す。
+    pub async fn flush_batch(&self) -> Result<()> {
+        let Some(batch) = &self.batch else {
+            return Ok(());
+        };
+        let buffered: Vec<Escalation> = std::mem::take(&mut *batch.buffer.lock().await);
+        if buffered.is_empty() {
+            return Ok(());
+        }
+        *batch.window_start.lock().await = None;
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let mut content = format!(
+            "# エスカレーションサマリ（{}件）\n\n",
+            buffered.len()
+        );
+        for escalation in &buffered {
+            content.push_str(&format!(
+                "- [{}] {} ({}): {}\n",
+                escalation.level.as_str(),
+                escalation.spec_id,
+                escalation.session_id,
+                escalation.reason,
+            ));
+        }
+        let filename = format!("batch-{}.md", Utc::now().format("%Y%m%d-%H%M%S%3f"));
+        tokio::fs::write(self.dir.join(filename), content).await?;
+
+        // バッファ中の最高レベルで1件のサマリ通知を出す
+        // （バッチ化の目的は通知の集約であり、欠落ではない）
+        if let Some(highest) = buffered.iter().max_by_key(|e| e.level) {
+            let summary = Escalation::new(
+                highest.session_id.clone(),
+                highest.spec_id.clone(),
+                highest.level,
+                format!("{}件のエスカレーションをバッチ集約", buffered.len()),
+            );
+            self.notify(&summary).await?;
+        }
+        Ok(())
+    }
+
+    /// 1件のエスカレーションをマークダウンファイルとして書き出す。
+    async fn write_record(&self, escalation: &Escalation) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let filename = format!(
+            "{}-{}.md",
+            escalation.occurred_at.format("%Y%m%d-%H%M%S%3f"),
+            escalation.session_id
+        );
+        let content = format!(
+            "# エスカレーション: {}\n\n- **セッション**: {}\n- **Spec**: {}\n- **レベル**: {}\n- **発生時刻**: {}\n\n## 理由\n\n{}\n",
+            escalation.level.as_str(),
+            escalation.session_id,
+            escalation.spec_id,
+            escalation.level.as_str(),
+            escalation.occurred_at.to_rfc3339(),
+            escalation.reason,
+        );
+        tokio::fs::write(self.dir.join(filename), content).await?;
+        Ok(())
+    }
+
+    /// 通知設定に従って外部通知を行う。
+    ///
+    /// 実際の HTTP 送信は未実装で、送信内容を `notifications.log` に
+    /// 追記する。無効時・レベル不足時は何もしない。
+    async fn notify(&self, escalation: &Escalation) -> Result<()> {
+        let Some(settings) = &self.notifications else {
+            return Ok(());
+        };
+        if !settings.enabled || escalation.level < settings.min_level {
+            return Ok(());
+        }
+        // TODO: Send to settings.webhook_url via HTTP
+        let line = format!(
+            "{} [{}] {} {} → {}\n",
+            escalation.occurred_at.to_rfc3339(),
+            escalation.level.as_str(),
+            escalation.spec_id,
+            escalation.reason,
+            settings.webhook_url.as_deref().unwrap_or("(webhook未設定)"),
+        );
+        let path = self.dir.join("notifications.log");
+        let existing = tokio::fs::read_to_string(&path).await.unwrap_or_default();
+        tokio::fs::write(&path, existing + &line).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_escalation(level: EscalationLevel) -> Escalation {
+        Escalation::new(
+            SessionId::from("sess-01"),
+            SpecId::from("SPEC-001"),
+            level,
+            "理由",
+        )
+    }
+
+    #[tokio::test]
+    async fn test_notification_written_when_enabled_and_level_met() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = EscalationHandler::new(dir.path()).with_notifications(
+            NotificationSettings {
+                enabled: true,
+                webhook_url: Some("https://example.com/hook".to_string()),
+                min_level: EscalationLevel::Warning,
+            },
+        );
+        handler
+            .handle(&sample_escalation(EscalationLevel::Critical))
+            .await
+            .unwrap();
+
+        let log = std::fs::read_to_string(dir.path().join("notifications.log")).unwrap();
+        assert!(log.contains("critical"));
+        assert!(log.contains("https://example.com/hook"));
+    }
+
+    #[tokio::test]
+    async fn test_no_notification_when_disabled_or_below_level() {
+        let dir = tempfile::tempdir().unwrap();
+        // 無効化されている
+        let disabled = EscalationHandler::new(dir.path()).with_notifications(
+            NotificationSettings {
+                enabled: false,
+                webhook_url: None,
+                min_level: EscalationLevel::Info,
+            },
+        );
+        disabled
+            .handle(&sample_escalation(EscalationLevel::Critical))
+            .await
+            .unwrap();
+        assert!(!dir.path().join("notifications.log").exists());
+
+        // レベル不足
+        let strict = EscalationHandler::new(dir.path()).with_notifications(
+            NotificationSettings {
+                enabled: true,
+                webhook_url: None,
+                min_level: EscalationLevel::Critical,
+            },
+        );
+        strict
+            .handle(&sample_escalation(EscalationLevel::Warning))
+            .await
+            .unwrap();
+        assert!(!dir.path().join("notifications.log").exists());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batching_buffers_and_flushes_summary() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = EscalationHandler::new(dir.path()).with_batching(60);
+
+        // ウィンドウ内の Warning はバッファされファイルは出ない
+        handler
+            .handle(&sample_escalation(EscalationLevel::Warning))
+            .await
+            .unwrap();
+        handler
+            .handle(&sample_escalation(EscalationLevel::Warning))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_dir(dir.path()).map(|d| d.count()).unwrap_or(0), 0);
+
+        // ウィンドウ経過後の次の1件でまとめてフラッシュされる
+        tokio::time::advance(Duration::from_secs(61)).await;
+        handler
+            .handle(&sample_escalation(EscalationLevel::Warning))
+            .await
+            .unwrap();
+
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+        let content =
+            std::fs::read_to_string(entries[0].as_ref().unwrap().path()).unwrap();
+        assert!(content.contains("3件"));
+    }
+
+    #[tokio::test]
+    async fn test_critical_bypasses_batching() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = EscalationHandler::new(dir.path()).with_batching(60);
+
+        handler
+            .handle(&sample_escalation(EscalationLevel::Critical))
+            .await
+            .unwrap();
+        // Critical は即時に個別ファイルが書かれる
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_handle_writes_markdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = EscalationHandler::new(dir.path());
+        let escalation = Escalation::new(
+            SessionId::from("sess-01"),
+            SpecId::from("SPEC-001"),
+            EscalationLevel::Critical,
+            "テストが3回連続で失敗",
+        );
+        handler.handle(&escalation).await.unwrap();
+
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+        let content =
+            std::fs::read_to_string(entries[0].as_ref().unwrap().path()).unwrap();
+        assert!(content.contains("SPEC-001"));
+        assert!(content.contains("critical"));
+    }
+}
@@ -0,0 +1,806 @@
+use crate::error::Result;
+use crate::services::completion_detector::{CompletionDetector, DetectionResult};
+use aad_domain::entities::Task;
+use aad_domain::repositories::TaskRepository;
+use aad_domain::value_objects::{SpecId, Status, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// ループ実行の永続状態（`.aad/loop-state.json`）。
+///
+/// TUI や `persist` コマンドがこのファイルを読んで進捗を表示する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopState {
+    pub spec_id: SpecId,
+    /// 現在実行中のタスク。
+    pub current_task: Option<TaskId>,
+    /// タスクごとのリトライ回数。
+    ///
+    /// Git diff が安定するよう、キー順が決定的な `BTreeMap` を使う
+    /// （`HashMap` だとシリアライズのたびにキー順が変わり diff がノイジー）。
+    pub retry_counts: BTreeMap<TaskId, u32>,
+    /// 完了済みタスク。`next_task` が O(1) でスキップ判定に使う。
+    /// 既存の JSON には無いフィールドなので欠落時は空とみなす。
+    #[serde(default)]
+    pub completed: BTreeSet<TaskId>,
+    /// リトライ上限に達して確定失敗したタスク。`next_task` は二度と返さない。
+    /// 既存の JSON には無いフィールドなので欠落時は空とみなす。
+    #[serde(default, alias = "failed_tasks")]
+    pub failed: BTreeSet<TaskId>,
+    pub paused: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoopState {
+    pub fn new(spec_id: SpecId) -> Self {
+        Self {
+            spec_id,
+            current_task: None,
+            retry_counts: BTreeMap::new(),
+            completed: BTreeSet::new(),
+            failed: BTreeSet::new(),
+            paused: false,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn get_retry_count(&self, id: &TaskId) -> u32 {
+        self.retry_counts.get(id).copied().unwrap_or(0)
+    }
+
+    /// リトライ回数を1増やし、新しい回数を返す。
+    pub fn increment_retry(&mut self, id: &TaskId) -> u32 {
+        let count = self.retry_counts.entry(id.clone()).or_insert(0);
+        *count += 1;
+        self.updated_at = Utc::now();
+        *count
+    }
+
+    /// タスクを完了として記録する。
+    pub fn mark_completed(&mut self, id: &TaskId) {
+        self.completed.insert(id.clone());
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_completed(&self, id: &TaskId) -> bool {
+        self.completed.contains(id)
+    }
+
+    /// タスクを確定失敗として記録する。
+    pub fn mark_failed_permanently(&mut self, id: &TaskId) {
+        self.failed.insert(id.clone());
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_failed_permanently(&self, id: &TaskId) -> bool {
+        self.failed.contains(id)
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+        self.updated_at = Utc::now();
+    }
+}
+
+/// タスク実行クロージャが返す Future。
+pub type TaskFuture =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<String>> + Send>>;
+
+/// タスクを実際に実行するランナー。
+///
+/// 実運用では Claude Code セッションを起動して出力を返すクロージャを
+/// 注入し、テストでは成功/失敗をモックする。
+pub type TaskRunner = Box<dyn Fn(&TaskId) -> TaskFuture + Send + Sync>;
+
+/// タスク中断時の扱い。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbortMode {
+    /// 失敗扱いにしてリトライ対象へ戻す。
+    Retry,
+    /// 確定失敗として以後スキップする。
+    Skip,
+}
+
+/// ループ実行中に発生するイベント。TUI や orchestrator が購読して
+/// リアルタイム表示に使う。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoopEvent {
+    TaskStarted(TaskId),
+    TaskCompleted(TaskId),
+    TaskFailed { task_id: TaskId, attempt: u32 },
+}
+
+/// ループ実行のサマリ。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoopSummary {
+    pub completed: usize,
+    pub skipped: usize,
+}
+
+/// Spec 内のタスクを順に消化するループエンジン。
+pub struct LoopEngine {
+    state: LoopState,
+    max_retries: u32,
+    detector: CompletionDetector,
+    state_path: PathBuf,
+    /// イベント購読者への送信チャネル。未設定なら何も送らない。
+    events_tx: Option<mpsc::UnboundedSender<LoopEvent>>,
+    /// タスク実行ランナー。未設定ならプレースホルダ（即完了）で動く。
+    runner: Option<TaskRunner>,
+    /// タスクごとの実行タイムアウト。None なら無制限。
+    task_timeout: Option<std::time::Duration>,
+    /// 実行中タスクの中断トークン。中断のたびに張り替える。
+    abort_token: CancellationToken,
+    /// 中断したタスクの扱い。
+    abort_mode: AbortMode,
+}
+
+impl LoopEngine {
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    pub fn new(spec_id: SpecId, detector: CompletionDetector) -> Self {
+        Self {
+            state: LoopState::new(spec_id),
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            detector,
+            state_path: PathBuf::from(".aad/loop-state.json"),
+            events_tx: None,
+            runner: None,
+            task_timeout: None,
+            abort_token: CancellationToken::new(),
+            abort_mode: AbortMode::Retry,
+        }
+    }
+
+    /// 中断時のタスクの扱い（リトライ/スキップ）を設定する。
+    pub fn with_abort_mode(mut self, mode: AbortMode) -> Self {
+        self.abort_mode = mode;
+        self
+    }
+
+    /// 実行中タスクを外部（TUI やシグナルハンドラ）から中断するための
+    /// トークンを返す。cancel すると現在のタスクだけが中断され、
+    /// ループは次のタスクへ進む。
+    pub fn abort_handle(&self) -> CancellationToken {
+        self.abort_token.clone()
+    }
+
+    /// 現在実行中のタスクを中断する。
+    ///
+    /// 実行中でなければ次に実行されるタスクが即座に中断される。
+    pub fn abort_current(&mut self) {
+        self.abort_token.cancel();
+    }
+
+    /// タスク実行ランナーを注入する。
+    ///
+    /// `run_loop` は各タスクをランナーで実行し、出力を
+    /// `CompletionDetector` に渡して完了パターンにマッチしたら
+    /// Completed、しなければ `mark_task_failed` でリトライに回す。
+    pub fn with_runner(mut self, runner: TaskRunner) -> Self {
+        self.runner = Some(runner);
+        self
+    }
+
+    /// タスクごとの実行タイムアウトを設定する。
+    ///
+    /// 長時間フリーズしたタスクがループ全体を止めないよう、超過した
+    /// タスクは失敗としてリトライに回し、上限を超えたら確定失敗として
+    /// 次のタスクへ進む。
+    pub fn with_task_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.task_timeout = Some(timeout);
+        self
+    }
+
+    /// イベント送信チャネルを設定する。
+    pub fn with_events(mut self, tx: mpsc::UnboundedSender<LoopEvent>) -> Self {
+        self.events_tx = Some(tx);
+        self
+    }
+
+    /// イベントを送信する。購読者がいなければ何もしない。
+    fn emit(&self, event: LoopEvent) {
+        if let Some(tx) = &self.events_tx {
+            // 受信側が閉じていても送信エラーは無視してよい
+            let _ = tx.send(event);
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_state_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.state_path = path.into();
+        self
+    }
+
+    pub fn state(&self) -> &LoopState {
+        &self.state
+    }
+
+    pub fn detector(&self) -> &CompletionDetector {
+        &self.detector
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// 次に実行すべきタスクのインデックスを返す。
+    ///
+    /// 完了セット/確定失敗セットにあるタスクは O(1) でスキップされる
+    /// （タスクスライスの status が古くても二重実行されない）。
+    pub fn next_task(&self, tasks: &[Task]) -> Option<usize> {
+        tasks.iter().position(|t| {
+            t.status != Status::Completed
+                && !self.state.is_completed(&t.id)
+                && !self.state.is_failed_permanently(&t.id)
+                && self.state.get_retry_count(&t.id) < self.max_retries
+                && t.depends_on.iter().all(|dep| {
+                    tasks
+                        .iter()
+                        .find(|other| other.id == *dep)
+                        .map(|other| other.is_completed())
+                        .unwrap_or(true)
+                })
+        })
+    }
+
+    /// タスクを失敗として記録する。リトライ回数を増やして返す。
+    ///
+    /// リトライ上限に達したタスクは `LoopState::failed` に確定失敗として
+    /// 記録され、`next_task` が二度と返さなくなる。
+    pub fn mark_task_failed(&mut self, id: &TaskId) -> u32 {
+        let attempt = self.state.increment_retry(id);
+        if attempt >= self.max_retries {
+            self.state.mark_failed_permanently(id);
+        }
+        self.emit(LoopEvent::TaskFailed {
+            task_id: id.clone(),
+            attempt,
+        });
+        attempt
+    }
+
+    /// タスクを順に消化する。
+    pub async fn run_loop(&mut self, tasks: &mut [Task]) -> Result<LoopSummary> {
+        let mut summary = LoopSummary::default();
+
+        while let Some(index) = self.next_task(tasks) {
+            if self.state.paused {
+                break;
+            }
+
+            let task_id = tasks[index].id.clone();
+            self.state.current_task = Some(task_id.clone());
+            self.emit(LoopEvent::TaskStarted(task_id));
+            self.save_state()?;
+
+            if self.execute_task(&mut tasks[index]).await? {
+                summary.completed += 1;
+            }
+
+            self.state.current_task = None;
+            self.state.updated_at = Utc::now();
+            self.save_state()?;
+        }
+
+        summary.skipped = tasks.iter().filter(|t| !t.is_completed()).count();
+        Ok(summary)
+    }
+
+    /// 1タスクを実行する。完了すれば `true`。
+    ///
+    /// ランナーが注入されていれば実行してその出力を完了検出器で判定し、
+    /// 未注入なら従来通りのプレースホルダ（即完了）として扱う。
+    async fn execute_task(&mut self, task: &mut Task) -> Result<bool> {
+        let Some(runner) = &self.runner else {
+            // In a real implementation, this would execute the task
+            // (spawn a Claude Code session) and feed its output to the
+            // completion detector.
+            task.change_status(Status::Completed);
+            self.state.mark_completed(&task.id);
+            self.emit(LoopEvent::TaskCompleted(task.id.clone()));
+            return Ok(true);
+        };
+
+        let future = (runner)(&task.id);
+        let abort = self.abort_token.clone();
+        // 中断トークンと競合させつつ、タイムアウト付きで実行（None なら無制限）
+        let result = tokio::select! {
+            _ = abort.cancelled() => None,
+            result = async {
+                match self.task_timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, future)
+                        .await
+                        .unwrap_or_else(|_elapsed| {
+                            Err(crate::ApplicationError::InvalidState(
+                                "task timed out".to_string(),
+                            ))
+                        }),
+                    None => future.await,
+                }
+            } => Some(result),
+        };
+
+        let Some(output) = result else {
+            // 中断された。トークンを張り替えて次のタスクに備える
+            self.abort_token = CancellationToken::new();
+            match self.abort_mode {
+                AbortMode::Retry => {
+                    self.mark_task_failed(&task.id);
+                }
+                AbortMode::Skip => {
+                    self.state.mark_failed_permanently(&task.id);
+                }
+            }
+            return Ok(false);
+        };
+        match output.map(|text| self.detector.detect(&text)) {
+            Ok(DetectionResult::Completed) => {
+                task.change_status(Status::Completed);
+                self.state.mark_completed(&task.id);
+                self.emit(LoopEvent::TaskCompleted(task.id.clone()));
+                Ok(true)
+            }
+            // 失敗検出・完了パターン不一致・実行エラーはリトライに回す
+            Ok(DetectionResult::Failed | DetectionResult::InProgress) | Err(_) => {
+                self.mark_task_failed(&task.id);
+                Ok(false)
+            }
+        }
+    }
+
+    /// イベントチャネル付きでループを実行する。
+    ///
+    /// タスクの開始・完了・失敗を `LoopEvent` として送信しながら
+    /// `run_loop` と同じ処理を行う。
+    pub async fn run_loop_with_events(
+        &mut self,
+        tasks: &mut [Task],
+        tx: mpsc::UnboundedSender<LoopEvent>,
+    ) -> Result<LoopSummary> {
+        self.events_tx = Some(tx);
+        self.run_loop(tasks).await
+    }
+
+    /// リポジトリからタスクを読み込んでループを実行し、完了したタスクの
+    /// ステータスをリポジトリへ保存する。
+    ///
+    /// `run_loop` と異なり、タスクの完了が `task_repo` に永続化されるため
+    /// 次回ロード時も進捗が保持される。
+    pub async fn run_loop_with_repo<R: TaskRepository>(
+        &mut self,
+        spec_id: &SpecId,
+        task_repo: &R,
+    ) -> Result<LoopSummary> {
+        let mut tasks = task_repo.find_by_spec_id(spec_id)?;
+        let mut summary = LoopSummary::default();
+
+        while let Some(index) = self.next_task(&tasks) {
+            if self.state.paused {
+                break;
+            }
+
+            let task_id = tasks[index].id.clone();
+            self.state.current_task = Some(task_id.clone());
+            self.emit(LoopEvent::TaskStarted(task_id));
+            self.save_state()?;
+
+            if self.execute_task(&mut tasks[index]).await? {
+                task_repo.save(&tasks[index])?;
+                summary.completed += 1;
+            }
+
+            self.state.current_task = None;
+            self.state.updated_at = Utc::now();
+            self.save_state()?;
+        }
+
+        summary.skipped = tasks.iter().filter(|t| !t.is_completed()).count();
+        Ok(summary)
+    }
+
+    /// 状態を state_path にアトミックに保存する。
+    pub fn save_state(&self) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.state)?;
+        crate::services::atomic_io::write_atomic(&self.state_path, &json)?;
+        Ok(())
+    }
+
+    /// 保存済みの状態を読み込む。
+    pub fn load_state(path: &Path) -> Result<LoopState> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aad_domain::value_objects::{Complexity, Priority};
+
+    fn make_task(id: &str) -> Task {
+        Task::new(
+            TaskId::from(id),
+            SpecId::from("SPEC-001"),
+            id,
+            Priority::Must,
+            Complexity::Small,
+        )
+    }
+
+    fn make_engine(dir: &Path) -> LoopEngine {
+        LoopEngine::new(
+            SpecId::from("SPEC-001"),
+            CompletionDetector::with_defaults().unwrap(),
+        )
+        .with_state_path(dir.join("loop-state.json"))
+    }
+
+    #[tokio::test]
+    async fn test_run_loop_completes_all_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path());
+        let mut tasks = vec![make_task("T01"), make_task("T02")];
+
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        assert_eq!(summary.completed, 2);
+        assert!(tasks.iter().all(|t| t.is_completed()));
+    }
+
+    #[test]
+    fn test_next_task_skips_retry_exhausted() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path()).with_max_retries(2);
+        let tasks = vec![make_task("T01"), make_task("T02")];
+
+        engine.mark_task_failed(&tasks[0].id);
+        engine.mark_task_failed(&tasks[0].id);
+        // T01 はリトライ上限なので T02 が返る
+        assert_eq!(engine.next_task(&tasks), Some(1));
+    }
+
+    #[test]
+    fn test_next_task_respects_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let engine = make_engine(dir.path());
+        let mut blocked = make_task("T02");
+        blocked.depends_on.push(TaskId::from("T01"));
+        let tasks = vec![blocked, make_task("T01")];
+
+        // T02 は T01 完了待ちなので T01（index 1）が先
+        assert_eq!(engine.next_task(&tasks), Some(1));
+    }
+
+    /// テスト用のインメモリ TaskRepository。
+    struct InMemoryTaskRepo {
+        tasks: std::sync::Mutex<Vec<Task>>,
+    }
+
+    impl InMemoryTaskRepo {
+        fn new(tasks: Vec<Task>) -> Self {
+            Self {
+                tasks: std::sync::Mutex::new(tasks),
+            }
+        }
+    }
+
+    impl TaskRepository for InMemoryTaskRepo {
+        fn save(&self, task: &Task) -> std::result::Result<(), aad_domain::repositories::RepositoryError> {
+            let mut tasks = self.tasks.lock().unwrap();
+            if let Some(existing) = tasks.iter_mut().find(|t| t.id == task.id) {
+                *existing = task.clone();
+            } else {
+                tasks.push(task.clone());
+            }
+            Ok(())
+        }
+
+        fn find_by_id(&self, id: &TaskId) -> std::result::Result<Option<Task>, aad_domain::repositories::RepositoryError> {
+            Ok(self.tasks.lock().unwrap().iter().find(|t| t.id == *id).cloned())
+        }
+
+        fn find_by_spec_id(&self, spec_id: &SpecId) -> std::result::Result<Vec<Task>, aad_domain::repositories::RepositoryError> {
+            Ok(self
+                .tasks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.spec_id == *spec_id)
+                .cloned()
+                .collect())
+        }
+
+        fn find_all(&self) -> std::result::Result<Vec<Task>, aad_domain::repositories::RepositoryError> {
+            Ok(self.tasks.lock().unwrap().clone())
+        }
+
+        fn delete(&self, _id: &TaskId) -> std::result::Result<(), aad_domain::repositories::RepositoryError> {
+            unimplemented!("not needed in tests")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_loop_with_repo_persists_completion() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_id = SpecId::from("SPEC-001");
+        let repo = InMemoryTaskRepo::new(vec![make_task("T01"), make_task("T02")]);
+        let mut engine = make_engine(dir.path());
+
+        let summary = engine.run_loop_with_repo(&spec_id, &repo).await.unwrap();
+        assert_eq!(summary.completed, 2);
+
+        // リポジトリから再ロードしても完了が保持されている
+        let reloaded = repo.find_by_spec_id(&spec_id).unwrap();
+        assert!(reloaded.iter().all(|t| t.is_completed()));
+    }
+
+    #[test]
+    fn test_below_retry_limit_is_not_finalized() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path()).with_max_retries(2);
+        let tasks = vec![make_task("T01")];
+
+        // 1回目の失敗はまだ上限未満なので対象に残る
+        engine.mark_task_failed(&tasks[0].id);
+        assert!(!engine.state().is_failed_permanently(&tasks[0].id));
+        assert_eq!(engine.next_task(&tasks), Some(0));
+    }
+
+    #[test]
+    fn test_finalized_task_is_never_returned_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path()).with_max_retries(2);
+        let tasks = vec![make_task("T01"), make_task("T02")];
+
+        engine.mark_task_failed(&tasks[0].id);
+        engine.mark_task_failed(&tasks[0].id);
+        // 上限到達で確定失敗として記録される
+        assert!(engine.state().is_failed_permanently(&tasks[0].id));
+        assert_eq!(engine.next_task(&tasks), Some(1));
+
+        // 状態を保存→再読込しても確定失敗が保持される
+        engine.save_state().unwrap();
+        let loaded =
+            LoopEngine::load_state(&dir.path().join("loop-state.json")).unwrap();
+        assert!(loaded.is_failed_permanently(&tasks[0].id));
+    }
+
+    #[tokio::test]
+    async fn test_runner_output_drives_completion_detection() {
+        let dir = tempfile::tempdir().unwrap();
+        // T01 は完了パターンを出力、T02 は出力しない
+        let runner: TaskRunner = Box::new(|id: &TaskId| {
+            let id = id.clone();
+            Box::pin(async move {
+                if id.as_str() == "T01" {
+                    Ok("完了：実装しました".to_string())
+                } else {
+                    Ok("まだ作業中です".to_string())
+                }
+            })
+        });
+        let mut engine = make_engine(dir.path())
+            .with_max_retries(2)
+            .with_runner(runner);
+        let mut tasks = vec![make_task("T01"), make_task("T02")];
+
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        assert_eq!(summary.completed, 1);
+        assert!(tasks[0].is_completed());
+        assert!(!tasks[1].is_completed());
+        // 完了パターン不一致はリトライ上限まで再試行されて確定失敗
+        assert!(engine.state().is_failed_permanently(&tasks[1].id));
+        assert_eq!(engine.state().get_retry_count(&tasks[1].id), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failing_runner_retries_until_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let runner: TaskRunner = {
+            let calls = calls.clone();
+            Box::new(move |_id: &TaskId| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                Box::pin(async { Err(crate::ApplicationError::InvalidState("boom".into())) })
+            })
+        };
+        let mut engine = make_engine(dir.path())
+            .with_max_retries(3)
+            .with_runner(runner);
+        let mut tasks = vec![make_task("T01")];
+
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        assert_eq!(summary.completed, 0);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 3);
+        assert!(engine.state().is_failed_permanently(&tasks[0].id));
+    }
+
+    #[tokio::test]
+    async fn test_abort_skips_current_task_and_continues() {
+        let dir = tempfile::tempdir().unwrap();
+        // T01 は永遠に終わらない、T02 は即完了
+        let runner: TaskRunner = Box::new(|id: &TaskId| {
+            let id = id.clone();
+            Box::pin(async move {
+                if id.as_str() == "T01" {
+                    std::future::pending::<()>().await;
+                    unreachable!()
+                }
+                Ok("完了：OK".to_string())
+            })
+        });
+        let mut engine = make_engine(dir.path())
+            .with_runner(runner)
+            .with_abort_mode(AbortMode::Skip);
+        let abort = engine.abort_handle();
+        let mut tasks = vec![make_task("T01"), make_task("T02")];
+
+        // T01 の実行が始まったところで中断する
+        let aborter = tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            abort.cancel();
+        });
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        aborter.await.unwrap();
+
+        // 中断された T01 はスキップ扱いになり、ループは T02 へ進んでいる
+        assert!(engine.state().is_failed_permanently(&tasks[0].id));
+        assert_eq!(engine.state().get_retry_count(&tasks[0].id), 0);
+        assert!(tasks[1].is_completed());
+        assert_eq!(summary.completed, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timed_out_task_does_not_block_others() {
+        let dir = tempfile::tempdir().unwrap();
+        // T01 は永遠に終わらない、T02 は即完了
+        let runner: TaskRunner = Box::new(|id: &TaskId| {
+            let id = id.clone();
+            Box::pin(async move {
+                if id.as_str() == "T01" {
+                    std::future::pending::<()>().await;
+                    unreachable!()
+                }
+                Ok("完了：OK".to_string())
+            })
+        });
+        let mut engine = make_engine(dir.path())
+            .with_max_retries(2)
+            .with_runner(runner)
+            .with_task_timeout(std::time::Duration::from_secs(5));
+        let mut tasks = vec![make_task("T01"), make_task("T02")];
+
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        // タイムアウトした T01 は確定失敗、T02 はブロックされず完了
+        assert!(engine.state().is_failed_permanently(&tasks[0].id));
+        assert!(tasks[1].is_completed());
+        assert_eq!(summary.completed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_timeout_means_unlimited() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner: TaskRunner = Box::new(|_id: &TaskId| {
+            Box::pin(async {
+                // タイムアウト未設定なら長めの処理でも完走する
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                Ok("完了：OK".to_string())
+            })
+        });
+        let mut engine = make_engine(dir.path()).with_runner(runner);
+        let mut tasks = vec![make_task("T01")];
+
+        let summary = engine.run_loop(&mut tasks).await.unwrap();
+        assert_eq!(summary.completed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_loop_with_events_emits_lifecycle_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path());
+        let mut tasks = vec![make_task("T01")];
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        engine.run_loop_with_events(&mut tasks, tx).await.unwrap();
+
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            LoopEvent::TaskStarted(TaskId::from("T01"))
+        );
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            LoopEvent::TaskCompleted(TaskId::from("T01"))
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_mark_task_failed_emits_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut engine = make_engine(dir.path()).with_events(tx);
+
+        engine.mark_task_failed(&TaskId::from("T01"));
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            LoopEvent::TaskFailed {
+                task_id: TaskId::from("T01"),
+                attempt: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_completed_set_skips_stale_task_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path());
+        let tasks = vec![make_task("T01"), make_task("T02")];
+
+        // 状態側で完了済みなら、タスクデータが古く Pending のままでも
+        // 再 dequeue されない
+        engine.state.mark_completed(&tasks[0].id);
+        assert_eq!(engine.next_task(&tasks), Some(1));
+    }
+
+    #[test]
+    fn test_completed_and_failed_sets_survive_save_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut engine = make_engine(dir.path());
+        engine.state.mark_completed(&TaskId::from("T01"));
+        engine.state.mark_failed_permanently(&TaskId::from("T02"));
+        engine.save_state().unwrap();
+
+        let loaded =
+            LoopEngine::load_state(&dir.path().join("loop-state.json")).unwrap();
+        assert!(loaded.is_completed(&TaskId::from("T01")));
+        assert!(loaded.is_failed_permanently(&TaskId::from("T02")));
+    }
+
+    #[test]
+    fn test_serialization_is_deterministic() {
+        // 挿入順が異なっても同じ内容なら常に同じバイト列になる
+        let mut a = LoopState::new(SpecId::from("SPEC-001"));
+        for id in ["T03", "T01", "T02"] {
+            a.increment_retry(&TaskId::from(id));
+            a.mark_failed_permanently(&TaskId::from(id));
+        }
+        let mut b = LoopState::new(SpecId::from("SPEC-001"));
+        for id in ["T01", "T02", "T03"] {
+            b.increment_retry(&TaskId::from(id));
+            b.mark_failed_permanently(&TaskId::from(id));
+        }
+        b.updated_at = a.updated_at;
+
+        let json_a = serde_json::to_string_pretty(&a).unwrap();
+        let json_b = serde_json::to_string_pretty(&b).unwrap();
+        assert_eq!(json_a, json_b);
+        // 同一インスタンスの再シリアライズも安定している
+        assert_eq!(json_a, serde_json::to_string_pretty(&a).unwrap());
+    }
+
+    #[test]
+    fn test_save_and_load_state_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("loop-state.json");
+        let mut engine = make_engine(dir.path());
+        engine.state.increment_retry(&TaskId::from("T01"));
+        engine.save_state().unwrap();
+
+        let loaded = LoopEngine::load_state(&path).unwrap();
+        assert_eq!(loaded.get_retry_count(&TaskId::from("T01")), 1);
+    }
+}
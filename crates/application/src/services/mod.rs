@@ -0,0 +1,21 @@
+//! アプリケーションサービス。
+
+pub mod atomic_io;
+pub mod completion_detector;
+pub mod dependency_graph;
+pub mod escalation;
+pub mod loop_engine;
+pub mod orchestrator;
+
+pub use atomic_io::write_atomic;
+pub use completion_detector::{
+    CompletionDetector, CompletionPatterns, DetectionResult, NamedPattern,
+};
+pub use dependency_graph::DependencyGraph;
+pub use escalation::{Escalation, EscalationHandler, EscalationLevel, NotificationSettings};
+pub use loop_engine::{AbortMode, LoopEngine, LoopEvent, LoopState, TaskFuture, TaskRunner};
+pub use orchestrator::{
+    EscalationNotice, FailureSummary, MonitorEvent, Orchestrator, OrchestratorConfig,
+    OrchestratorState,
+    ResourceLimits, ResourceMonitor, SessionSummary, SpecPin, SyncPolicy, SystemResourceMonitor,
+};
@@ -0,0 +1,264 @@
+use aad_domain::entities::{Session, Spec, Task};
+use clap::Args;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct DoctorArgs {}
+
+/// doctor が検出する不整合。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoctorIssue {
+    /// 存在しない Spec を参照するタスク。
+    OrphanTask { task_id: String, spec_id: String },
+    /// タスクが1つも無い Spec。
+    SpecWithoutTasks { spec_id: String },
+    /// パースできない JSON ファイル。
+    BrokenJson { path: String, error: String },
+    /// 存在しないタスクへの依存。
+    MissingDependency { task_id: String, depends_on: String },
+    /// 存在しない Spec を参照するセッション。
+    OrphanSession { session_id: String, spec_id: String },
+}
+
+impl fmt::Display for DoctorIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoctorIssue::OrphanTask { task_id, spec_id } => {
+                write!(f, "孤児タスク: {task_id} が存在しない Spec {spec_id} を参照")
+            }
+            DoctorIssue::SpecWithoutTasks { spec_id } => {
+                write!(f, "タスク無し Spec: {spec_id}")
+            }
+            DoctorIssue::BrokenJson { path, error } => {
+                write!(f, "壊れた JSON: {path} — {error}")
+            }
+            DoctorIssue::MissingDependency { task_id, depends_on } => {
+                write!(f, "存在しない依存: {task_id} → {depends_on}")
+            }
+            DoctorIssue::OrphanSession { session_id, spec_id } => {
+                write!(f, "孤児セッション: {session_id} が存在しない Spec {spec_id} を参照")
+            }
+        }
+    }
+}
+
+/// Spec/Task/Session リポジトリを横断して整合性をチェックする。
+/// 問題があれば詳細を表示して終了コード1で終わる。
+pub fn execute(_args: DoctorArgs) -> anyhow::Result<()> {
+    let issues = diagnose(
+        &super::specs_dir(),
+        &super::tasks_dir(),
+        &super::sessions_dir(),
+    )?;
+
+    if issues.is_empty() {
+        println!("✅ 完了：不整合は見つかりませんでした");
+        return Ok(());
+    }
+
+    println!("❌ {}件の不整合が見つかりました:", issues.len());
+    for issue in &issues {
+        println!("  - {issue}");
+    }
+    std::process::exit(1);
+}
+
+/// 3つのリポジトリディレクトリを走査して不整合を収集する。
+///
+/// 壊れた JSON はエラーで中断せず `BrokenJson` として報告し、
+/// 残りのチェックを続行する。
+pub(crate) fn diagnose(
+    specs_dir: &Path,
+    tasks_dir: &Path,
+    sessions_dir: &Path,
+) -> anyhow::Result<Vec<DoctorIssue>> {
+    let mut issues = Vec::new();
+
+    let specs = read_entities::<Spec>(specs_dir, &mut issues)?;
+    let spec_ids: HashSet<String> = specs.iter().map(|s| s.id.to_string()).collect();
+
+    // タスクは .aad/data/tasks/<spec_id>/ のサブディレクトリに入っている
+    let mut tasks: Vec<Task> = Vec::new();
+    if tasks_dir.exists() {
+        for entry in std::fs::read_dir(tasks_dir)? {
+            let dir = entry?.path();
+            if dir.is_dir() {
+                tasks.extend(read_entities::<Task>(&dir, &mut issues)?);
+            }
+        }
+    }
+    let task_ids: HashSet<String> = tasks.iter().map(|t| t.id.to_string()).collect();
+
+    let sessions = read_entities::<Session>(sessions_dir, &mut issues)?;
+
+    // 孤児タスク
+    for task in &tasks {
+        if !spec_ids.contains(task.spec_id.as_str()) {
+            issues.push(DoctorIssue::OrphanTask {
+                task_id: task.id.to_string(),
+                spec_id: task.spec_id.to_string(),
+            });
+        }
+        // 存在しない依存
+        for dep in &task.depends_on {
+            if !task_ids.contains(dep.as_str()) {
+                issues.push(DoctorIssue::MissingDependency {
+                    task_id: task.id.to_string(),
+                    depends_on: dep.to_string(),
+                });
+            }
+        }
+    }
+
+    // タスク無し Spec
+    let specs_with_tasks: HashSet<String> =
+        tasks.iter().map(|t| t.spec_id.to_string()).collect();
+    for spec in &specs {
+        if !specs_with_tasks.contains(spec.id.as_str()) {
+            issues.push(DoctorIssue::SpecWithoutTasks {
+                spec_id: spec.id.to_string(),
+            });
+        }
+    }
+
+    // 孤児セッション
+    for session in &sessions {
+        if !spec_ids.contains(session.spec_id.as_str()) {
+            issues.push(DoctorIssue::OrphanSession {
+                session_id: session.id.to_string(),
+                spec_id: session.spec_id.to_string(),
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+/// ディレクトリ内の JSON をパースし、壊れたファイルは issues に積む。
+fn read_entities<T: serde::de::DeserializeOwned>(
+    dir: &Path,
+    issues: &mut Vec<DoctorIssue>,
+) -> anyhow::Result<Vec<T>> {
+    let mut entities = Vec::new();
+    if !dir.exists() {
+        return Ok(entities);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        match serde_json::from_str::<T>(&content) {
+            Ok(entity) => entities.push(entity),
+            Err(e) => issues.push(DoctorIssue::BrokenJson {
+                path: path.display().to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    Ok(entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aad_domain::repositories::{SpecRepository, TaskRepository};
+    use aad_domain::value_objects::{Complexity, Priority, SpecId, TaskId};
+    use aad_infrastructure::persistence::{SpecJsonRepo, TaskJsonRepo};
+
+    fn make_task(spec: &str, id: &str) -> Task {
+        Task::new(
+            TaskId::from(id),
+            SpecId::from(spec),
+            id,
+            Priority::Must,
+            Complexity::Small,
+        )
+    }
+
+    #[test]
+    fn test_detects_orphan_task_and_missing_dependency() {
+        let dir = tempfile::tempdir().unwrap();
+        let specs = dir.path().join("specs");
+        let tasks = dir.path().join("tasks");
+        let sessions = dir.path().join("sessions");
+
+        SpecJsonRepo::new(&specs)
+            .save(&Spec::new(SpecId::from("SPEC-001"), "a", "d"))
+            .unwrap();
+        let task_repo = TaskJsonRepo::new(&tasks);
+        task_repo.save(&make_task("SPEC-001", "SPEC-001-T01")).unwrap();
+        // SPEC-999 は存在しない
+        let mut orphan = make_task("SPEC-999", "SPEC-999-T01");
+        orphan.depends_on.push(TaskId::from("SPEC-999-T00"));
+        task_repo.save(&orphan).unwrap();
+
+        let issues = diagnose(&specs, &tasks, &sessions).unwrap();
+        assert!(issues.contains(&DoctorIssue::OrphanTask {
+            task_id: "SPEC-999-T01".to_string(),
+            spec_id: "SPEC-999".to_string(),
+        }));
+        assert!(issues.contains(&DoctorIssue::MissingDependency {
+            task_id: "SPEC-999-T01".to_string(),
+            depends_on: "SPEC-999-T00".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_detects_spec_without_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let specs = dir.path().join("specs");
+        SpecJsonRepo::new(&specs)
+            .save(&Spec::new(SpecId::from("SPEC-001"), "a", "d"))
+            .unwrap();
+
+        let issues = diagnose(
+            &specs,
+            &dir.path().join("tasks"),
+            &dir.path().join("sessions"),
+        )
+        .unwrap();
+        assert_eq!(
+            issues,
+            vec![DoctorIssue::SpecWithoutTasks {
+                spec_id: "SPEC-001".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_detects_broken_json_without_aborting() {
+        let dir = tempfile::tempdir().unwrap();
+        let specs = dir.path().join("specs");
+        std::fs::create_dir_all(&specs).unwrap();
+        std::fs::write(specs.join("SPEC-001.json"), "{not json").unwrap();
+
+        let issues = diagnose(
+            &specs,
+            &dir.path().join("tasks"),
+            &dir.path().join("sessions"),
+        )
+        .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0], DoctorIssue::BrokenJson { .. }));
+    }
+
+    #[test]
+    fn test_clean_project_has_no_issues() {
+        let dir = tempfile::tempdir().unwrap();
+        let specs = dir.path().join("specs");
+        let tasks = dir.path().join("tasks");
+        SpecJsonRepo::new(&specs)
+            .save(&Spec::new(SpecId::from("SPEC-001"), "a", "d"))
+            .unwrap();
+        TaskJsonRepo::new(&tasks)
+            .save(&make_task("SPEC-001", "SPEC-001-T01"))
+            .unwrap();
+
+        let issues = diagnose(&specs, &tasks, &dir.path().join("sessions")).unwrap();
+        assert!(issues.is_empty());
+    }
+}
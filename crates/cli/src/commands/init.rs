@@ -0,0 +1,144 @@
+use aad_domain::entities::{AcceptanceCriterion, Spec, Task};
+use aad_domain::repositories::{SpecRepository, TaskRepository};
+use aad_domain::value_objects::{Complexity, Priority, SpecId, TaskId};
+use aad_infrastructure::config::AadConfig;
+use aad_infrastructure::persistence::{SpecJsonRepo, TaskJsonRepo};
+use clap::Args;
+use std::fs;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct InitArgs {
+    /// 初期化先ディレクトリ（デフォルト: カレント）
+    #[arg(long, default_value = ".")]
+    pub path: String,
+
+    /// チュートリアル用のサンプル Spec（SPEC-000）とタスクを生成する
+    #[arg(long)]
+    pub with_example: bool,
+}
+
+/// `.aad` ディレクトリ構造と設定ファイルの雛形を生成する。
+pub fn execute(args: InitArgs) -> anyhow::Result<()> {
+    let root = Path::new(&args.path);
+    init_project(root)?;
+    if args.with_example {
+        generate_example(root)?;
+        crate::style::styled_println(
+            "completion",
+            "完了：",
+            ".aad プロジェクト構造とサンプル（SPEC-000）を初期化しました",
+        );
+        println!("  次のコマンドで試せます: aad status / aad loop SPEC-000");
+    } else {
+        crate::style::styled_println("completion", "完了：", ".aad プロジェクト構造を初期化しました");
+    }
+    Ok(())
+}
+
+pub(crate) fn init_project(root: &Path) -> anyhow::Result<()> {
+    let aad = root.join(".aad");
+    for dir in [
+        "data/specs",
+        "data/tasks",
+        "data/sessions",
+        "specs",
+        "retrospectives",
+        "escalations",
+        "orchestration",
+        "backups",
+    ] {
+        fs::create_dir_all(aad.join(dir))?;
+    }
+
+    let config_path = aad.join("config.toml");
+    if !config_path.exists() {
+        // 全セクションのコメント付きデフォルトを出し、設定項目を一覧できるようにする
+        fs::write(&config_path, AadConfig::generate_template())?;
+    }
+    Ok(())
+}
+
+/// サンプルの Spec とタスクを `.aad/data/` に生成する。
+pub(crate) fn generate_example(root: &Path) -> anyhow::Result<()> {
+    let spec_repo = SpecJsonRepo::new(root.join(".aad/data/specs"));
+    let task_repo = TaskJsonRepo::new(root.join(".aad/data/tasks"));
+
+    let spec_id = SpecId::from("SPEC-000");
+    let mut spec = Spec::new(
+        spec_id.clone(),
+        "チュートリアル",
+        "aad の使い方を体験するためのサンプル Spec です。",
+    );
+    spec.add_criterion(AcceptanceCriterion {
+        id: "AC-01".to_string(),
+        description: "aad status で進捗が確認できる".to_string(),
+        priority: Priority::Must,
+    });
+    spec.add_criterion(AcceptanceCriterion {
+        id: "AC-02".to_string(),
+        description: "aad loop SPEC-000 でタスクが消化される".to_string(),
+        priority: Priority::Should,
+    });
+    spec_repo.save(&spec)?;
+
+    let samples = [
+        ("SPEC-000-T01", "status コマンドを試す", Priority::Must, Complexity::Small, Some("AC-01")),
+        ("SPEC-000-T02", "loop コマンドを試す", Priority::Must, Complexity::Small, Some("AC-02")),
+        ("SPEC-000-T03", "gate コマンドを試す", Priority::Should, Complexity::Medium, None),
+    ];
+    for (id, name, priority, complexity, criterion) in samples {
+        let mut task = Task::new(TaskId::from(id), spec_id.clone(), name, priority, complexity);
+        if let Some(ac) = criterion {
+            task.satisfies_criteria.push(ac.to_string());
+        }
+        task_repo.save(&task)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_creates_structure() {
+        let dir = tempfile::tempdir().unwrap();
+        init_project(dir.path()).unwrap();
+        assert!(dir.path().join(".aad/data/specs").is_dir());
+        assert!(dir.path().join(".aad/config.toml").is_file());
+        // 雛形はそのまま AadConfig で読める
+        let config =
+            AadConfig::load(&dir.path().join(".aad/config.toml")).unwrap();
+        assert_eq!(config.orchestration.max_parallel_specs, 2);
+    }
+
+    #[test]
+    fn test_example_is_readable_from_repos() {
+        let dir = tempfile::tempdir().unwrap();
+        init_project(dir.path()).unwrap();
+        generate_example(dir.path()).unwrap();
+
+        let spec_repo = SpecJsonRepo::new(dir.path().join(".aad/data/specs"));
+        let task_repo = TaskJsonRepo::new(dir.path().join(".aad/data/tasks"));
+
+        let spec = spec_repo
+            .find_by_id(&SpecId::from("SPEC-000"))
+            .unwrap()
+            .expect("sample spec should exist");
+        assert_eq!(spec.acceptance_criteria.len(), 2);
+
+        let tasks = task_repo.find_by_spec_id(&SpecId::from("SPEC-000")).unwrap();
+        assert_eq!(tasks.len(), 3);
+    }
+
+    #[test]
+    fn test_init_does_not_overwrite_config() {
+        let dir = tempfile::tempdir().unwrap();
+        init_project(dir.path()).unwrap();
+        fs::write(dir.path().join(".aad/config.toml"), "custom = true\n").unwrap();
+        init_project(dir.path()).unwrap();
+        let content = fs::read_to_string(dir.path().join(".aad/config.toml")).unwrap();
+        assert_eq!(content, "custom = true\n");
+    }
+}
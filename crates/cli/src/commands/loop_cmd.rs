@@ -0,0 +1,117 @@
+use aad_application::services::{CompletionDetector, LoopEngine};
+use aad_domain::entities::Task;
+use aad_domain::repositories::TaskRepository;
+use aad_domain::value_objects::{Complexity, Priority, SpecId};
+use aad_infrastructure::persistence::TaskJsonRepo;
+use clap::Args;
+
+#[derive(Args)]
+pub struct LoopArgs {
+    /// 対象の Spec ID
+    pub spec_id: String,
+
+    /// タスクごとの最大リトライ回数
+    #[arg(long, default_value_t = LoopEngine::DEFAULT_MAX_RETRIES)]
+    pub max_retries: u32,
+
+    /// この優先度のタスクだけを実行する（must/should/could/wont）
+    #[arg(long)]
+    pub priority: Option<String>,
+
+    /// この複雑度のタスクだけを実行する（small/medium/large）
+    #[arg(long)]
+    pub complexity: Option<String>,
+}
+
+/// Spec のタスクを順に消化するループを実行する。
+pub async fn execute(args: LoopArgs) -> anyhow::Result<()> {
+    let spec_id = SpecId::from(args.spec_id.as_str());
+    let task_repo = TaskJsonRepo::new(super::tasks_dir());
+
+    let priority = args
+        .priority
+        .as_deref()
+        .map(|p| p.parse::<Priority>().map_err(|e| anyhow::anyhow!(e)))
+        .transpose()?;
+    let complexity = args
+        .complexity
+        .as_deref()
+        .map(|c| c.parse::<Complexity>().map_err(|e| anyhow::anyhow!(e)))
+        .transpose()?;
+
+    let all_tasks = task_repo.find_by_spec_id(&spec_id)?;
+    let mut tasks = apply_filters(all_tasks, priority, complexity);
+
+    if tasks.is_empty() {
+        println!("⚠️ {spec_id} に対象タスクがありません");
+        return Ok(());
+    }
+
+    let detector = CompletionDetector::with_defaults()?;
+    let mut engine =
+        LoopEngine::new(spec_id.clone(), detector).with_max_retries(args.max_retries);
+
+    // フィルタで対象外になったタスクはキューに入れない。完了した
+    // タスクはリポジトリへ保存され、次回ロード時も進捗が保持される
+    let summary = engine.run_loop(&mut tasks).await?;
+    for task in tasks.iter().filter(|t| t.is_completed()) {
+        task_repo.save(task)?;
+    }
+    crate::style::styled_println(
+        "completion",
+        "完了：",
+        &format!("{} タスク完了 / {} タスク残り", summary.completed, summary.skipped),
+    );
+    Ok(())
+}
+
+/// 優先度/複雑度フィルタを適用する。None の条件は素通し。
+pub(crate) fn apply_filters(
+    tasks: Vec<Task>,
+    priority: Option<Priority>,
+    complexity: Option<Complexity>,
+) -> Vec<Task> {
+    tasks
+        .into_iter()
+        .filter(|t| priority.is_none_or(|p| t.priority == p))
+        .filter(|t| complexity.is_none_or(|c| t.complexity == c))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aad_domain::value_objects::TaskId;
+
+    fn make_task(id: &str, priority: Priority, complexity: Complexity) -> Task {
+        Task::new(
+            TaskId::from(id),
+            SpecId::from("SPEC-001"),
+            id,
+            priority,
+            complexity,
+        )
+    }
+
+    #[test]
+    fn test_filters_select_expected_tasks() {
+        let tasks = vec![
+            make_task("T01", Priority::Must, Complexity::Small),
+            make_task("T02", Priority::Should, Complexity::Small),
+            make_task("T03", Priority::Must, Complexity::Large),
+        ];
+
+        // Must のみ
+        let must = apply_filters(tasks.clone(), Some(Priority::Must), None);
+        assert_eq!(must.len(), 2);
+
+        // Must かつ Small
+        let must_small =
+            apply_filters(tasks.clone(), Some(Priority::Must), Some(Complexity::Small));
+        assert_eq!(must_small.len(), 1);
+        assert_eq!(must_small[0].id, TaskId::from("T01"));
+
+        // フィルタ無しは全件
+        assert_eq!(apply_filters(tasks, None, None).len(), 3);
+    }
+}
@@ -0,0 +1,36 @@
+//! CLI サブコマンドの実装。
+
+pub mod doctor;
+pub mod gate;
+pub mod init;
+pub mod integrate;
+pub mod loop_cmd;
+pub mod monitor;
+pub mod orchestrate;
+pub mod persist;
+pub mod retro;
+pub mod spec;
+pub mod status;
+pub mod tasks;
+
+use std::path::PathBuf;
+
+/// `.aad` ルートディレクトリ。
+pub fn aad_dir() -> PathBuf {
+    PathBuf::from(".aad")
+}
+
+/// Spec リポジトリのベースディレクトリ。
+pub fn specs_dir() -> PathBuf {
+    aad_dir().join("data/specs")
+}
+
+/// Task リポジトリのベースディレクトリ。
+pub fn tasks_dir() -> PathBuf {
+    aad_dir().join("data/tasks")
+}
+
+/// Session リポジトリのベースディレクトリ。
+pub fn sessions_dir() -> PathBuf {
+    aad_dir().join("data/sessions")
+}
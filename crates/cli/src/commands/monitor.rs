@@ -0,0 +1,44 @@
+use aad_tui::{App, Theme, ThemeName};
+use clap::Args;
+
+#[derive(Args)]
+pub struct MonitorArgs {
+    /// ループ状態ファイルのパス
+    #[arg(long, default_value = ".aad/loop-state.json")]
+    pub loop_state: String,
+
+    /// 読み取り専用モード（pause/resume 等の変更操作を無効化）
+    #[arg(long)]
+    pub readonly: bool,
+
+    /// 表示テーマ（dark/light/high-contrast）。t キーで実行中も切替可
+    #[arg(long, default_value = "dark")]
+    pub theme: String,
+
+    /// 切り替え対象のプロジェクトルート（複数指定可、[ / ] キーで切替）
+    #[arg(long = "project")]
+    pub projects: Vec<std::path::PathBuf>,
+
+    /// 状態ファイルの再読み込み間隔（ミリ秒）
+    #[arg(long, default_value_t = 500)]
+    pub refresh_ms: u64,
+}
+
+/// TUI ダッシュボードを起動する。
+pub fn execute(args: MonitorArgs) -> anyhow::Result<()> {
+    let theme_name: ThemeName = args
+        .theme
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    let mut app = App::new()
+        .with_loop_state_path(&args.loop_state)
+        .with_readonly(args.readonly)
+        .with_theme(Theme::by_name(theme_name))
+        .with_sessions_dir(super::sessions_dir())
+        .with_tasks_dir(super::tasks_dir())
+        .with_refresh_interval(std::time::Duration::from_millis(args.refresh_ms));
+    if !args.projects.is_empty() {
+        app = app.with_projects(args.projects);
+    }
+    aad_tui::run(app)
+}
@@ -0,0 +1,352 @@
+use aad_application::services::{Orchestrator, OrchestratorConfig, SpecPin};
+use aad_domain::repositories::SpecRepository;
+use aad_domain::value_objects::{Phase, SpecId};
+use aad_infrastructure::config::AadConfig;
+use aad_infrastructure::persistence::{load_spec_dependencies, SpecJsonRepo};
+use clap::Args;
+
+#[derive(Args)]
+pub struct OrchestrateArgs {
+    /// 実行対象の Spec ID（複数指定可）
+    #[arg(required_unless_present = "all")]
+    pub specs: Vec<String>,
+
+    /// リポジトリの全 Active Spec を対象にする
+    #[arg(long)]
+    pub all: bool,
+
+    /// 実行計画の表示のみで実際には起動しない
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// 保存済み状態から再開する
+    #[arg(long)]
+    pub resume: bool,
+
+    /// dry-run の出力形式（text/dot）
+    #[arg(long, default_value = "text")]
+    pub format: String,
+
+    /// 全体の制限時間（秒）。超過すると残りセッションを中断する
+    #[arg(long)]
+    pub max_duration: Option<u64>,
+
+    /// 実行結果を JSON で書き出すファイルパス
+    #[arg(long)]
+    pub result_file: Option<std::path::PathBuf>,
+
+    /// セッション状態を CSV で書き出すファイルパス
+    #[arg(long)]
+    pub export_csv: Option<std::path::PathBuf>,
+
+    /// 最初のウェーブへピン留めする Spec（複数指定可）
+    #[arg(long = "pin-first")]
+    pub pin_first: Vec<String>,
+
+    /// 最後のウェーブへピン留めする Spec（複数指定可）
+    #[arg(long = "pin-last")]
+    pub pin_last: Vec<String>,
+}
+
+pub async fn execute(args: OrchestrateArgs) -> anyhow::Result<()> {
+    if args.dry_run {
+        execute_dry_run(&args).await
+    } else {
+        execute_normal(&args).await
+    }
+}
+
+/// `.aad/config.toml` を反映した OrchestratorConfig を組み立てる。
+///
+/// 現状は [notifications] セクション（通知先・最小レベル）を反映する。
+fn orchestrator_config() -> anyhow::Result<OrchestratorConfig> {
+    let aad_config = AadConfig::load_or_default(&super::aad_dir().join("config.toml"))?;
+    let mut config = OrchestratorConfig::default();
+    let notifications = aad_config.notification_config();
+    if notifications.enabled {
+        config.notifications = Some(notifications.to_settings()?);
+    }
+    config.escalation_batch_window_secs = notifications.batch_window_secs;
+    Ok(config)
+}
+
+/// Active でない Spec を除外して実行対象を返す。
+///
+/// リポジトリに存在しない ID（未保存の Spec）はそのまま対象に含める。
+fn filter_active_specs(specs: &[String]) -> anyhow::Result<Vec<String>> {
+    let spec_repo = SpecJsonRepo::new(super::specs_dir());
+    let mut active = Vec::new();
+    for spec in specs {
+        match spec_repo.find_by_id(&SpecId::from(spec.as_str()))? {
+            Some(found) if !found.is_active() => {
+                println!("⚠️ {spec} は {} のため対象外です", found.lifecycle);
+            }
+            _ => active.push(spec.clone()),
+        }
+    }
+    Ok(active)
+}
+
+/// 実行計画（ウェーブ分割）を表示するだけのドライラン。
+async fn execute_dry_run(args: &OrchestrateArgs) -> anyhow::Result<()> {
+    let orchestrator = Orchestrator::new(orchestrator_config()?);
+    register_targets(&orchestrator, args).await?;
+    match args.format.as_str() {
+        "dot" => print!("{}", orchestrator.graph_dot().await),
+        "text" => print_execution_plan(&orchestrator).await?,
+        other => anyhow::bail!("不明な出力形式: {other}（text/dot）"),
+    }
+    Ok(())
+}
+
+/// --all またはコマンドラインの Spec ID をセッションとして登録する。
+async fn register_targets(
+    orchestrator: &Orchestrator,
+    args: &OrchestrateArgs,
+) -> anyhow::Result<()> {
+    for spec in &args.pin_first {
+        orchestrator
+            .pin_spec(&SpecId::from(spec.as_str()), SpecPin::First)
+            .await;
+    }
+    for spec in &args.pin_last {
+        orchestrator
+            .pin_spec(&SpecId::from(spec.as_str()), SpecPin::Last)
+            .await;
+    }
+    if args.all {
+        let spec_repo = SpecJsonRepo::new(super::specs_dir());
+        let count =
+            register_all(orchestrator, &spec_repo, &super::aad_dir().join("specs")).await?;
+        println!("📦 全 Active Spec を登録しました ({count}件)");
+        return Ok(());
+    }
+
+    let targets = filter_active_specs(&args.specs)?;
+    register_with_dependencies(orchestrator, &targets, &super::aad_dir().join("specs")).await
+}
+
+/// 全 Active Spec を dependencies.json の依存付きで一括登録する。
+pub(crate) async fn register_all(
+    orchestrator: &Orchestrator,
+    spec_repo: &SpecJsonRepo,
+    specs_dir: &std::path::Path,
+) -> anyhow::Result<usize> {
+    let ids = orchestrator
+        .register_all_from_repo(spec_repo, Phase::Tdd, |id| {
+            load_spec_dependencies(specs_dir, id)
+                .map(|deps| deps.depends_on)
+                .map_err(|e| {
+                    aad_application::ApplicationError::Config(format!(
+                        "{id} の依存関係ファイルを読めません: {e}"
+                    ))
+                })
+        })
+        .await?;
+    Ok(ids.len())
+}
+
+/// 各 Spec の dependencies.json を読み込んで依存付きで登録する。
+///
+/// 存在しない依存先（対象外の Spec）を指定していたらエラーで早期終了し、
+/// 循環依存は register_spec の循環検査がエラーにする。
+pub(crate) async fn register_with_dependencies(
+    orchestrator: &Orchestrator,
+    targets: &[String],
+    specs_dir: &std::path::Path,
+) -> anyhow::Result<()> {
+    for spec in targets {
+        let spec_id = SpecId::from(spec.as_str());
+        let deps = load_spec_dependencies(specs_dir, &spec_id)?.depends_on;
+        for dep in &deps {
+            if !targets.iter().any(|t| t == dep.as_str()) {
+                anyhow::bail!(
+                    "{spec} の依存先 {dep} が実行対象に存在しません"
+                );
+            }
+        }
+        orchestrator
+            .register_spec(&spec_id, Phase::Tdd, &deps)
+            .await?;
+    }
+    Ok(())
+}
+
+/// 全セッションを依存順に起動する。
+async fn execute_normal(args: &OrchestrateArgs) -> anyhow::Result<()> {
+    let orchestrator = Orchestrator::new(orchestrator_config()?);
+
+    if args.resume {
+        orchestrator.restore_state().await?;
+        println!("🔄 保存済み状態から再開します");
+    }
+
+    register_targets(&orchestrator, args).await?;
+
+    print_execution_plan(&orchestrator).await?;
+    // 子プロセス起動が未実装で完了通知が来ないため、並列制限の permit
+    // 待ちでコマンドが固まらないよう上限をセッション数に合わせておく。
+    // TODO: Remove once child process completion reporting lands
+    let count = orchestrator.session_count().await;
+    orchestrator.set_max_parallel(count.max(1));
+    orchestrator.start_all_sessions().await?;
+
+    // タイムボックス: 制限時間内に完了しなければ残りをキャンセルする
+    if let Some(secs) = args.max_duration {
+        let completed = orchestrator
+            .monitor_loop_with_deadline(std::time::Duration::from_secs(secs))
+            .await?;
+        if !completed {
+            println!("⏱ 制限時間（{secs}秒）を超過したため未完了セッションを中断しました");
+        }
+    }
+    orchestrator.save_state().await?;
+
+    // スプレッドシート分析向けにセッション状態を CSV で書き出す
+    if let Some(path) = &args.export_csv {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, orchestrator.export_csv().await)?;
+        println!("📄 CSV を書き出しました: {}", path.display());
+    }
+
+    // CI の後続ステップ向けに集約結果を JSON で書き出す
+    if let Some(path) = &args.result_file {
+        let result = orchestrator.build_result().await;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&result)?)?;
+        println!("📄 実行結果を書き出しました: {}", path.display());
+    }
+
+    // 失敗があれば依存の観点で根本原因と連鎖失敗を分類して表示する
+    let failures = orchestrator.summarize_failures().await;
+    if !failures.root_causes.is_empty() || !failures.cascaded.is_empty() {
+        println!("❌ 失敗サマリ:");
+        if !failures.root_causes.is_empty() {
+            println!("  根本原因: {}", failures.root_causes.join(", "));
+        }
+        if !failures.cascaded.is_empty() {
+            println!("  連鎖失敗: {}", failures.cascaded.join(", "));
+        }
+    }
+
+    let progress = orchestrator.calculate_progress().await;
+    println!("結果：完了率 {progress:.0}%");
+    Ok(())
+}
+
+
+/// ウェーブ分割と最大並列度を表示する。
+async fn print_execution_plan(orchestrator: &Orchestrator) -> anyhow::Result<()> {
+    let groups = orchestrator.get_parallel_execution_groups().await?;
+
+    println!("📋 実行計画:");
+    let mut max_parallel = 0;
+    for (i, wave) in groups.iter().enumerate() {
+        println!("  ウェーブ {}: {}", i + 1, wave.join(", "));
+        max_parallel = max_parallel.max(wave.len());
+    }
+    println!("  最大並列度: {max_parallel}");
+    // 所要時間は未計測のためデフォルト値ベースの概算
+    if let Ok((_, total)) = orchestrator.estimate_completion().await {
+        println!("  最短完了見積もり: 約{}分", total.as_secs() / 60);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_deps(specs_dir: &std::path::Path, spec: &str, deps: &[&str]) {
+        let dir = specs_dir.join(spec);
+        std::fs::create_dir_all(&dir).unwrap();
+        let deps: Vec<String> = deps.iter().map(|d| format!("\"{d}\"")).collect();
+        std::fs::write(
+            dir.join("dependencies.json"),
+            format!("{{\"depends_on\": [{}]}}", deps.join(", ")),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_waves_reflect_dependency_files() {
+        let dir = tempfile::tempdir().unwrap();
+        write_deps(dir.path(), "SPEC-002", &["SPEC-001"]);
+
+        let orchestrator = Orchestrator::new(OrchestratorConfig::default());
+        register_with_dependencies(
+            &orchestrator,
+            &["SPEC-001".to_string(), "SPEC-002".to_string()],
+            dir.path(),
+        )
+        .await
+        .unwrap();
+
+        let groups = orchestrator.get_parallel_execution_groups().await.unwrap();
+        assert_eq!(groups[0], vec!["SPEC-001"]);
+        assert_eq!(groups[1], vec!["SPEC-002"]);
+    }
+
+    #[tokio::test]
+    async fn test_register_all_loads_dependency_files() {
+        use aad_domain::entities::Spec;
+        use aad_domain::repositories::SpecRepository;
+
+        let dir = tempfile::tempdir().unwrap();
+        let spec_repo = SpecJsonRepo::new(dir.path().join("data/specs"));
+        spec_repo
+            .save(&Spec::new(SpecId::from("SPEC-001"), "a", "d"))
+            .unwrap();
+        spec_repo
+            .save(&Spec::new(SpecId::from("SPEC-002"), "b", "d"))
+            .unwrap();
+        let specs_dir = dir.path().join("specs");
+        write_deps(&specs_dir, "SPEC-002", &["SPEC-001"]);
+
+        let orchestrator = Orchestrator::new(OrchestratorConfig::default());
+        let count = register_all(&orchestrator, &spec_repo, &specs_dir)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+
+        // --all でも dependencies.json の順序がウェーブに反映される
+        let groups = orchestrator.get_parallel_execution_groups().await.unwrap();
+        assert_eq!(groups[0], vec!["SPEC-001"]);
+        assert_eq!(groups[1], vec!["SPEC-002"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_dependency_target_fails_early() {
+        let dir = tempfile::tempdir().unwrap();
+        write_deps(dir.path(), "SPEC-001", &["SPEC-404"]);
+
+        let orchestrator = Orchestrator::new(OrchestratorConfig::default());
+        let err = register_with_dependencies(
+            &orchestrator,
+            &["SPEC-001".to_string()],
+            dir.path(),
+        )
+        .await;
+        assert!(err.unwrap_err().to_string().contains("SPEC-404"));
+    }
+
+    #[tokio::test]
+    async fn test_cyclic_dependency_files_error() {
+        let dir = tempfile::tempdir().unwrap();
+        write_deps(dir.path(), "SPEC-001", &["SPEC-002"]);
+        write_deps(dir.path(), "SPEC-002", &["SPEC-001"]);
+
+        let orchestrator = Orchestrator::new(OrchestratorConfig::default());
+        let err = register_with_dependencies(
+            &orchestrator,
+            &["SPEC-001".to_string(), "SPEC-002".to_string()],
+            dir.path(),
+        )
+        .await;
+        assert!(err.is_err());
+    }
+}
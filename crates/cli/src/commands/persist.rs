@@ -0,0 +1,229 @@
+use aad_infrastructure::adapters::file::backup_adapter::BackupAdapter;
+use aad_infrastructure::config::AadConfig;
+use clap::{Args, Subcommand};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct PersistArgs {
+    #[command(subcommand)]
+    pub action: PersistAction,
+}
+
+#[derive(Subcommand)]
+pub enum PersistAction {
+    /// 状態ファイルをバックアップする
+    Save,
+    /// バックアップから復元する
+    Restore {
+        /// 復元するバックアップのタイムスタンプ（ファイル名の一部）
+        timestamp: String,
+
+        /// 復元対象と現在との差分を表示するだけで実際には戻さない
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// バックアップ対象の状態ファイル。
+fn state_files(config: &AadConfig) -> Vec<PathBuf> {
+    vec![
+        config.loop_engine.state_file.clone(),
+        config.persistence.state_dir.join("state.json"),
+    ]
+}
+
+pub fn execute(args: PersistArgs) -> anyhow::Result<()> {
+    // [persistence] セクションで保存先をカスタマイズできる。
+    // 設定が無ければ従来のデフォルト（.aad/backups 等）にフォールバック
+    let config = AadConfig::load_or_default(&super::aad_dir().join("config.toml"))?;
+    let adapter = BackupAdapter::new(config.persistence.backup_dir.clone());
+    match args.action {
+        PersistAction::Save => save(&adapter, &config),
+        PersistAction::Restore { timestamp, dry_run } => {
+            restore(&adapter, &config, &timestamp, dry_run)
+        }
+    }
+}
+
+fn save(adapter: &BackupAdapter, config: &AadConfig) -> anyhow::Result<()> {
+    let mut count = 0;
+    for file in state_files(config) {
+        if file.exists() {
+            let backup = adapter.backup(&file)?;
+            println!("💾 {} → {}", file.display(), backup.display());
+            count += 1;
+        }
+    }
+    adapter.cleanup_old_backups(config.persistence.keep_count)?;
+    crate::style::styled_println(
+        "completion",
+        "完了：",
+        &format!("{count}件の状態ファイルを保存しました"),
+    );
+    Ok(())
+}
+
+fn restore(
+    adapter: &BackupAdapter,
+    config: &AadConfig,
+    timestamp: &str,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let backups = adapter.list_backups()?;
+    let matching: Vec<_> = backups
+        .iter()
+        .filter(|b| {
+            b.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.contains(timestamp))
+        })
+        .collect();
+
+    if matching.is_empty() {
+        anyhow::bail!("タイムスタンプ {timestamp} に一致するバックアップがありません");
+    }
+
+    for backup in matching {
+        let target = original_path_for(config, backup)?;
+        if dry_run {
+            print_restore_preview(backup, &target)?;
+        } else if BackupAdapter::checksum_exists(backup) {
+            // 破損したバックアップで上書きしないよう、サイドカーが
+            // あればチェックサム照合付きで復元する
+            adapter.restore_verified(backup, &target)?;
+            println!("♻️ {} → {}（チェックサム照合済み）", backup.display(), target.display());
+        } else {
+            // チェックサム導入以前の古いバックアップは照合なしで復元する
+            println!("⚠️ チェックサムが無いため照合なしで復元します: {}", backup.display());
+            adapter.restore(backup, &target)?;
+            println!("♻️ {} → {}", backup.display(), target.display());
+        }
+    }
+    if dry_run {
+        println!("💡 dry-run のためファイルは変更していません");
+    } else {
+        crate::style::styled_println("completion", "完了：", "復元しました");
+    }
+    Ok(())
+}
+
+/// dry-run 時に復元対象と現在との差分サマリを表示する。
+fn print_restore_preview(backup: &Path, target: &Path) -> anyhow::Result<()> {
+    println!("復元対象: {} → {}", backup.display(), target.display());
+    // .bak.gz は生バイトではなく解凍した内容で差分を取る
+    let backup_content =
+        String::from_utf8_lossy(&BackupAdapter::read_content(backup)?).into_owned();
+    let current_content = if target.exists() {
+        std::fs::read_to_string(target)?
+    } else {
+        String::new()
+    };
+    let (added, removed) = diff_summary(&current_content, &backup_content);
+    if added == 0 && removed == 0 {
+        println!("  差分なし");
+    } else {
+        println!("  差分: +{added} 行 / -{removed} 行");
+    }
+    Ok(())
+}
+
+/// `old` から `new` へ変わる際に追加/削除される行数を数える簡易差分。
+fn diff_summary(old: &str, new: &str) -> (usize, usize) {
+    use std::collections::HashMap;
+    let mut counts: HashMap<&str, i64> = HashMap::new();
+    for line in new.lines() {
+        *counts.entry(line).or_insert(0) += 1;
+    }
+    for line in old.lines() {
+        *counts.entry(line).or_insert(0) -= 1;
+    }
+    let added = counts.values().filter(|&&c| c > 0).map(|&c| c as usize).sum();
+    let removed = counts
+        .values()
+        .filter(|&&c| c < 0)
+        .map(|&c| (-c) as usize)
+        .sum();
+    (added, removed)
+}
+
+
+/// バックアップファイル名から元のパスを導く。
+///
+/// `.bak` と `.bak.gz` の両形式を扱えるよう、パースは
+/// `BackupAdapter::original_name` に委譲する。
+fn original_path_for(config: &AadConfig, backup: &Path) -> anyhow::Result<PathBuf> {
+    let original = BackupAdapter::original_name(backup).ok_or_else(|| {
+        anyhow::anyhow!("不正なバックアップファイル名: {}", backup.display())
+    })?;
+    state_files(config)
+        .into_iter()
+        .find(|f| {
+            f.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n == original)
+        })
+        .ok_or_else(|| anyhow::anyhow!("{original} は既知の状態ファイルではありません"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_summary_counts_changed_lines() {
+        assert_eq!(diff_summary("a\nb\n", "a\nc\n"), (1, 1));
+        assert_eq!(diff_summary("a\n", "a\n"), (0, 0));
+        assert_eq!(diff_summary("", "a\nb\n"), (2, 0));
+    }
+
+    #[test]
+    fn test_original_path_resolves_compressed_backups() {
+        let config = AadConfig::default();
+        // 非圧縮・圧縮の両形式で loop-state.json に解決される
+        for name in [
+            "loop-state.json.20260901-120000000.bak",
+            "loop-state.json.20260901-120000000.bak.gz",
+        ] {
+            let resolved = original_path_for(&config, Path::new(name)).unwrap();
+            assert_eq!(resolved, PathBuf::from(".aad/loop-state.json"));
+        }
+        // 既知の状態ファイルでない名前はエラー
+        assert!(original_path_for(
+            &config,
+            Path::new("unknown.json.20260901-120000000.bak.gz")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_dry_run_does_not_modify_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("loop-state.json");
+        std::fs::write(&target, "original").unwrap();
+
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup = adapter.backup(&target).unwrap();
+        std::fs::write(&target, "modified").unwrap();
+
+        // dry-run では復元されない（original_path_for は既知の状態ファイル名を
+        // 前提とするため preview だけを直接検証する）
+        print_restore_preview(&backup, &target).unwrap();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "modified");
+    }
+
+    #[test]
+    fn test_dry_run_preview_handles_compressed_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("loop-state.json");
+        std::fs::write(&target, "line1\nline2\n").unwrap();
+
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup = adapter.backup_compressed(&target).unwrap();
+        std::fs::write(&target, "line1\nchanged\n").unwrap();
+
+        // 生の gzip バイトを read_to_string して UTF-8 エラーにならず、
+        // 解凍した内容で差分が取れる
+        print_restore_preview(&backup, &target).unwrap();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "line1\nchanged\n");
+    }
+}
@@ -0,0 +1,252 @@
+use chrono::Utc;
+use clap::Args;
+use std::fs;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct RetroArgs {
+    /// 対象の Spec ID
+    pub spec_id: String,
+
+    /// 学びのタイトル
+    #[arg(long, default_value = "")]
+    pub title: String,
+
+    /// 学びの内容
+    #[arg(long, default_value = "")]
+    pub learning: String,
+
+    /// 課題（Problem）。複数指定可
+    #[arg(long = "problem")]
+    pub problems: Vec<String>,
+}
+
+/// 振り返りを `.aad/retrospectives/` に記録し、CLAUDE.md に学びを追記する。
+pub fn execute(args: RetroArgs) -> anyhow::Result<()> {
+    let date = Utc::now().format("%Y-%m-%d");
+    let retro_dir = super::aad_dir().join("retrospectives");
+    fs::create_dir_all(&retro_dir)?;
+
+    // 過去の retro から繰り返し現れている課題を検出して警告する
+    let previous_problems = collect_previous_problems(&retro_dir, &args.spec_id)?;
+    for problem in &args.problems {
+        if let Some(past) = find_similar(problem, &previous_problems) {
+            println!("⚠️ 前回も同じ課題が出ています: 「{past}」 ≈ 「{problem}」");
+        }
+    }
+
+    let problems_md: String = args
+        .problems
+        .iter()
+        .map(|p| format!("- {p}\n"))
+        .collect();
+    let retro_path = retro_dir.join(format!("RETRO-{}-{}.md", args.spec_id, date));
+    let content = format!(
+        "# 振り返り: {}\n\n**日付**: {}\n\n## Keep\n\n## Problem\n\n{}\n## Try\n\n## 学び\n\n{}\n",
+        args.spec_id, date, problems_md, args.learning,
+    );
+    fs::write(&retro_path, content)?;
+    println!("📝 振り返りを記録しました: {}", retro_path.display());
+
+    if !args.learning.is_empty() {
+        let entry = format!(
+            "### {} - {}: {}\n\n{}\n",
+            date, args.spec_id, args.title, args.learning
+        );
+        append_to_claude_md(Path::new("CLAUDE.md"), &entry)?;
+        println!("📚 CLAUDE.md に学びを追記しました");
+    }
+    Ok(())
+}
+
+/// 過去の `RETRO-<spec>-*.md` から Problem セクションの項目を集める。
+pub(crate) fn collect_previous_problems(
+    retro_dir: &Path,
+    spec_id: &str,
+) -> anyhow::Result<Vec<String>> {
+    let mut problems = Vec::new();
+    if !retro_dir.exists() {
+        return Ok(problems);
+    }
+    let prefix = format!("RETRO-{spec_id}-");
+    for entry in fs::read_dir(retro_dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !name.starts_with(&prefix) || !name.ends_with(".md") {
+            continue;
+        }
+        problems.extend(extract_problems(&fs::read_to_string(&path)?));
+    }
+    Ok(problems)
+}
+
+/// 振り返りファイルの `## Problem` セクションから箇条書き項目を取り出す。
+pub(crate) fn extract_problems(content: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut in_section = false;
+    for line in content.lines() {
+        if line.starts_with("## ") {
+            in_section = line.trim() == "## Problem";
+            continue;
+        }
+        if in_section {
+            if let Some(item) = line.trim().strip_prefix("- ") {
+                problems.push(item.to_string());
+            }
+        }
+    }
+    problems
+}
+
+/// キーワード一致の簡易判定で類似する過去の課題を探す。
+///
+/// 文字バイグラムの Jaccard 係数が閾値以上、または片方がもう片方を
+/// 含む場合に類似とみなす（日本語にも対応するための簡易判定）。
+pub(crate) fn find_similar<'a>(problem: &str, previous: &'a [String]) -> Option<&'a str> {
+    const THRESHOLD: f64 = 0.4;
+    // 極端に短い文言は包含判定が誤検出だらけになるので除外する
+    const MIN_CONTAINS_LEN: usize = 4;
+    previous
+        .iter()
+        .find(|past| {
+            let containment = problem.chars().count() >= MIN_CONTAINS_LEN
+                && past.chars().count() >= MIN_CONTAINS_LEN
+                && (past.contains(problem) || problem.contains(past.as_str()));
+            containment || bigram_jaccard(problem, past) >= THRESHOLD
+        })
+        .map(|s| s.as_str())
+}
+
+fn bigram_jaccard(a: &str, b: &str) -> f64 {
+    let bigrams = |s: &str| -> std::collections::HashSet<(char, char)> {
+        let chars: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+        chars.windows(2).map(|w| (w[0], w[1])).collect()
+    };
+    let (set_a, set_b) = (bigrams(a), bigrams(b));
+    if set_a.is_empty() || set_b.is_empty() {
+        return 0.0;
+    }
+    let intersection = set_a.intersection(&set_b).count();
+    let union = set_a.union(&set_b).count();
+    intersection as f64 / union as f64
+}
+
+/// CLAUDE.md の「学びの蓄積」セクションに学びを追記する。
+pub(crate) fn append_to_claude_md(claude_md: &Path, entry: &str) -> anyhow::Result<()> {
+    let content = fs::read_to_string(claude_md)?;
+    let new_content = insert_under_header(&content, "## 🧠 学びの蓄積", entry);
+    fs::write(claude_md, new_content)?;
+    Ok(())
+}
+
+/// 見出しの直後にエントリを挿入する。見出しが無ければ末尾に
+/// セクションごと追記する。
+///
+/// 以前はマルチバイト絵文字を含む見出しのバイト長を 20 と決め打ちして
+/// おり、見出し文言が変わるとオフセットがずれて壊れていた。実バイト長
+/// （`header.len()`）を使って安全にスライスする。
+pub(crate) fn insert_under_header(content: &str, header: &str, entry: &str) -> String {
+    let Some(pos) = content.find(header) else {
+        return format!("{content}\n{header}\n\n{entry}\n");
+    };
+    let after_header = &content[pos + header.len()..];
+    let insert_at = pos
+        + header.len()
+        + after_header
+            .find("\n\n")
+            .map(|p| p + 2)
+            .unwrap_or(after_header.len());
+    format!(
+        "{}{}\n{}",
+        &content[..insert_at],
+        entry,
+        &content[insert_at..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_inserts_under_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("CLAUDE.md");
+        fs::write(&path, "# タイトル\n\n## 🧠 学びの蓄積\n\n既存の学び\n").unwrap();
+
+        append_to_claude_md(&path, "### 新しい学び\n").unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let header_pos = content.find("## 🧠 学びの蓄積").unwrap();
+        let new_pos = content.find("### 新しい学び").unwrap();
+        let old_pos = content.find("既存の学び").unwrap();
+        assert!(header_pos < new_pos);
+        assert!(new_pos < old_pos);
+    }
+
+    #[test]
+    fn test_insert_under_headers_of_various_byte_lengths() {
+        // 20バイトではないマルチバイト見出しでも正しく挿入される
+        for header in ["## 学び", "## 🧠 学びの蓄積メモランダム", "## Notes"] {
+            let content = format!("# Top\n\n{header}\n\n既存\n");
+            let result = insert_under_header(&content, header, "### 新規\n");
+            let header_pos = result.find(header).unwrap();
+            let new_pos = result.find("### 新規").unwrap();
+            let old_pos = result.find("既存").unwrap();
+            assert!(header_pos < new_pos, "header={header}");
+            assert!(new_pos < old_pos, "header={header}");
+        }
+    }
+
+    #[test]
+    fn test_extract_problems_reads_only_problem_section() {
+        let content = "# 振り返り\n\n## Keep\n\n- よかったこと\n\n## Problem\n\n- テストが不安定\n- ビルドが遅い\n\n## Try\n\n- 改善案\n";
+        assert_eq!(
+            extract_problems(content),
+            vec!["テストが不安定", "ビルドが遅い"]
+        );
+    }
+
+    #[test]
+    fn test_find_similar_detects_recurring_problem() {
+        let previous = vec![
+            "テストが不安定で再実行が必要".to_string(),
+            "ドキュメントが古い".to_string(),
+        ];
+        // ほぼ同じ文言は検出される
+        assert!(find_similar("テストが不安定", &previous).is_some());
+        // 無関係な課題は検出されない
+        assert!(find_similar("依存の更新漏れ", &previous).is_none());
+    }
+
+    #[test]
+    fn test_collect_previous_problems_filters_by_spec() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("RETRO-SPEC-001-2026-08-01.md"),
+            "## Problem\n\n- 課題A\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("RETRO-SPEC-002-2026-08-01.md"),
+            "## Problem\n\n- 課題B\n",
+        )
+        .unwrap();
+
+        let problems = collect_previous_problems(dir.path(), "SPEC-001").unwrap();
+        assert_eq!(problems, vec!["課題A"]);
+    }
+
+    #[test]
+    fn test_append_creates_section_when_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("CLAUDE.md");
+        fs::write(&path, "# タイトル\n").unwrap();
+
+        append_to_claude_md(&path, "### 学び\n").unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("## 🧠 学びの蓄積"));
+        assert!(content.contains("### 学び"));
+    }
+}
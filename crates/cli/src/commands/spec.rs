@@ -0,0 +1,273 @@
+use aad_domain::entities::Spec;
+use aad_domain::repositories::{SpecRepository, TaskRepository};
+use aad_domain::value_objects::{SpecId, TaskId};
+use aad_infrastructure::persistence::{SpecJsonRepo, TaskJsonRepo};
+use clap::Args;
+
+#[derive(Args)]
+pub struct SpecArgs {
+    /// Spec 名
+    pub name: String,
+
+    /// Spec の説明
+    #[arg(long, default_value = "", conflicts_with = "description_file")]
+    pub description: String,
+
+    /// 説明を読み込むファイル（既存の設計ドキュメントの流用向け）
+    #[arg(long)]
+    pub description_file: Option<std::path::PathBuf>,
+
+    /// 明示的な Spec ID（省略時は自動生成）
+    #[arg(long)]
+    pub id: Option<String>,
+}
+
+/// Spec を作成してリポジトリに保存する。
+pub fn execute(args: SpecArgs) -> anyhow::Result<()> {
+    let repo = SpecJsonRepo::new(super::specs_dir());
+    let spec = create_spec(&repo, args)?;
+    crate::style::styled_println(
+        "completion",
+        "完了：",
+        &format!("Spec を作成しました: {} ({})", spec.name, spec.id),
+    );
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct SpecCloneArgs {
+    /// 複製元の Spec ID
+    pub source: String,
+
+    /// 複製先の新しい Spec ID
+    pub target: String,
+
+    /// タスクも複製する
+    #[arg(long)]
+    pub with_tasks: bool,
+}
+
+/// 既存 Spec を雛形として新しい ID の Spec を作る。
+pub fn execute_clone(args: SpecCloneArgs) -> anyhow::Result<()> {
+    let spec_repo = SpecJsonRepo::new(super::specs_dir());
+    let task_repo = TaskJsonRepo::new(super::tasks_dir());
+    let spec = clone_spec(
+        &spec_repo,
+        &task_repo,
+        &SpecId::from(args.source.as_str()),
+        &SpecId::from(args.target.as_str()),
+        args.with_tasks,
+    )?;
+    crate::style::styled_println(
+        "completion",
+        "完了：",
+        &format!("Spec を複製しました: {} → {}", args.source, spec.id),
+    );
+    Ok(())
+}
+
+/// Spec（description, acceptance_criteria）を複製する。
+/// `with_tasks` ならタスクも新 ID で複製する。
+pub(crate) fn clone_spec(
+    spec_repo: &SpecJsonRepo,
+    task_repo: &TaskJsonRepo,
+    source: &SpecId,
+    target: &SpecId,
+    with_tasks: bool,
+) -> anyhow::Result<Spec> {
+    let original = spec_repo
+        .find_by_id(source)?
+        .ok_or_else(|| anyhow::anyhow!("複製元の Spec が見つかりません: {source}"))?;
+    if spec_repo.find_by_id(target)?.is_some() {
+        anyhow::bail!("複製先の Spec が既に存在します: {target}");
+    }
+
+    let mut spec = Spec::new(target.clone(), original.name.clone(), original.description);
+    spec.acceptance_criteria = original.acceptance_criteria;
+    spec_repo.save(&spec)?;
+
+    if with_tasks {
+        // タスク ID は複製元の spec ID プレフィックスを付け替える
+        let remap = |id: &TaskId| -> TaskId {
+            match id.as_str().strip_prefix(source.as_str()) {
+                Some(rest) => TaskId::from(format!("{target}{rest}")),
+                None => TaskId::from(format!("{target}-{id}")),
+            }
+        };
+        for mut task in task_repo.find_by_spec_id(source)? {
+            task.id = remap(&task.id);
+            task.spec_id = target.clone();
+            task.depends_on = task.depends_on.iter().map(&remap).collect();
+            task_repo.save(&task)?;
+        }
+    }
+    Ok(spec)
+}
+
+/// --description-file が大きすぎる場合に警告する閾値（64KiB）。
+const DESCRIPTION_SIZE_WARN_BYTES: u64 = 64 * 1024;
+
+pub(crate) fn create_spec(repo: &SpecJsonRepo, args: SpecArgs) -> anyhow::Result<Spec> {
+    // 明示指定が無ければ SPEC-NNN の連番で採番する
+    let id = match args.id {
+        Some(id) => SpecId::from(id),
+        None => repo.next_spec_id()?,
+    };
+
+    let description = match &args.description_file {
+        Some(path) => {
+            if !path.exists() {
+                anyhow::bail!("説明ファイルが見つかりません: {}", path.display());
+            }
+            let size = std::fs::metadata(path)?.len();
+            if size > DESCRIPTION_SIZE_WARN_BYTES {
+                println!(
+                    "⚠️ 説明ファイルが大きすぎます（{size} bytes）。要約を検討してください"
+                );
+            }
+            std::fs::read_to_string(path)?
+        }
+        None => args.description,
+    };
+
+    let spec = Spec::new(id, args.name, description);
+    repo.save(&spec)?;
+    Ok(spec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(name: &str) -> SpecArgs {
+        SpecArgs {
+            name: name.to_string(),
+            description: String::new(),
+            description_file: None,
+            id: Some("SPEC-001".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_create_spec_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        let spec = create_spec(
+            &repo,
+            SpecArgs {
+                description: "JWT 認証".to_string(),
+                ..args("auth")
+            },
+        )
+        .unwrap();
+        assert_eq!(spec.id, SpecId::from("SPEC-001"));
+        assert!(repo.find_by_id(&spec.id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_description_loaded_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path().join("specs"));
+        let doc = dir.path().join("design.md");
+        std::fs::write(&doc, "# 設計
+既存ドキュメントの内容").unwrap();
+
+        let spec = create_spec(
+            &repo,
+            SpecArgs {
+                description_file: Some(doc),
+                ..args("auth")
+            },
+        )
+        .unwrap();
+        assert!(spec.description.contains("既存ドキュメントの内容"));
+    }
+
+    #[test]
+    fn test_clone_copies_content_under_new_id() {
+        use aad_domain::entities::{AcceptanceCriterion, Task};
+        use aad_domain::value_objects::{Complexity, Priority};
+
+        let dir = tempfile::tempdir().unwrap();
+        let spec_repo = SpecJsonRepo::new(dir.path().join("specs"));
+        let task_repo = TaskJsonRepo::new(dir.path().join("tasks"));
+
+        let mut original = Spec::new(SpecId::from("SPEC-001"), "auth", "元の説明");
+        original.add_criterion(AcceptanceCriterion {
+            id: "AC-01".to_string(),
+            description: "ログインできる".to_string(),
+            priority: Priority::Must,
+        });
+        spec_repo.save(&original).unwrap();
+
+        let mut task = Task::new(
+            TaskId::from("SPEC-001-T02"),
+            SpecId::from("SPEC-001"),
+            "t",
+            Priority::Must,
+            Complexity::Small,
+        );
+        task.depends_on.push(TaskId::from("SPEC-001-T01"));
+        task_repo.save(&task).unwrap();
+
+        let cloned = clone_spec(
+            &spec_repo,
+            &task_repo,
+            &SpecId::from("SPEC-001"),
+            &SpecId::from("SPEC-002"),
+            true,
+        )
+        .unwrap();
+
+        // 内容は同じで新 ID を持つ
+        assert_eq!(cloned.id, SpecId::from("SPEC-002"));
+        assert_eq!(cloned.description, "元の説明");
+        assert_eq!(cloned.acceptance_criteria.len(), 1);
+
+        // タスクも新 ID で複製され、依存も付け替えられる
+        let tasks = task_repo.find_by_spec_id(&SpecId::from("SPEC-002")).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id, TaskId::from("SPEC-002-T02"));
+        assert_eq!(tasks[0].depends_on, vec![TaskId::from("SPEC-002-T01")]);
+
+        // 複製元は変更されない
+        assert!(spec_repo.find_by_id(&SpecId::from("SPEC-001")).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_clone_without_tasks_skips_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_repo = SpecJsonRepo::new(dir.path().join("specs"));
+        let task_repo = TaskJsonRepo::new(dir.path().join("tasks"));
+        spec_repo
+            .save(&Spec::new(SpecId::from("SPEC-001"), "a", "d"))
+            .unwrap();
+
+        clone_spec(
+            &spec_repo,
+            &task_repo,
+            &SpecId::from("SPEC-001"),
+            &SpecId::from("SPEC-002"),
+            false,
+        )
+        .unwrap();
+        assert!(task_repo
+            .find_by_spec_id(&SpecId::from("SPEC-002"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_missing_description_file_is_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        let err = create_spec(
+            &repo,
+            SpecArgs {
+                description_file: Some(dir.path().join("nope.md")),
+                ..args("auth")
+            },
+        );
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use aad_domain::repositories::{SessionRepository, SpecRepository, TaskRepository};
+use aad_domain::value_objects::Status;
+use aad_infrastructure::persistence::{SessionJsonRepo, SpecJsonRepo, TaskJsonRepo};
+use clap::Args;
+
+#[derive(Args)]
+pub struct StatusArgs {}
+
+/// プロジェクト全体の進捗を表示する。
+pub fn execute(_args: StatusArgs) -> anyhow::Result<()> {
+    let spec_repo = SpecJsonRepo::new(super::specs_dir());
+    let task_repo = TaskJsonRepo::new(super::tasks_dir());
+    let session_repo = SessionJsonRepo::new(super::sessions_dir());
+
+    let specs = spec_repo.find_all()?;
+    println!("📊 進捗: Spec {}件", specs.len());
+
+    for spec in &specs {
+        let tasks = task_repo.find_by_spec_id(&spec.id)?;
+        let completed = tasks
+            .iter()
+            .filter(|t| t.status == Status::Completed)
+            .count();
+        // Active 以外はライフサイクルを明示する
+        let lifecycle_tag = if spec.is_active() {
+            String::new()
+        } else {
+            format!(" [{}]", spec.lifecycle)
+        };
+        println!(
+            "  {} — {}{} ({}/{} タスク完了)",
+            spec.id,
+            spec.name,
+            lifecycle_tag,
+            completed,
+            tasks.len()
+        );
+    }
+
+    let active = session_repo.find_active()?;
+    println!("  アクティブセッション: {}件", active.len());
+    Ok(())
+}
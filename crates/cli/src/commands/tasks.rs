@@ -0,0 +1,31 @@
+use aad_domain::repositories::TaskRepository;
+use aad_domain::value_objects::SpecId;
+use aad_infrastructure::persistence::TaskJsonRepo;
+use clap::Args;
+
+#[derive(Args)]
+pub struct TasksArgs {
+    /// 対象の Spec ID
+    pub spec_id: String,
+}
+
+/// Spec に属するタスクの一覧を表示する。
+pub fn execute(args: TasksArgs) -> anyhow::Result<()> {
+    let repo = TaskJsonRepo::new(super::tasks_dir());
+    let spec_id = SpecId::from(args.spec_id);
+    let tasks = repo.find_by_spec_id(&spec_id)?;
+
+    if tasks.is_empty() {
+        println!("⚠️ {spec_id} にタスクがありません");
+        return Ok(());
+    }
+
+    println!("📋 {spec_id} のタスク ({}件):", tasks.len());
+    for task in &tasks {
+        println!(
+            "  [{}] {} ({}, {}, {})",
+            task.status, task.id, task.name, task.priority, task.complexity
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! `aad` — Agentic AI-Driven development CLI。
+
+mod commands;
+mod style;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aad", version, about = "Agentic AI-Driven development workflow")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// プロジェクトを初期化する
+    Init(commands::init::InitArgs),
+    /// Spec を作成する
+    Spec(commands::spec::SpecArgs),
+    /// 既存 Spec を雛形に複製する
+    SpecClone(commands::spec::SpecCloneArgs),
+    /// Spec のタスク一覧を表示する
+    Tasks(commands::tasks::TasksArgs),
+    /// 複数 Spec を依存順に全自動実行する
+    Orchestrate(commands::orchestrate::OrchestrateArgs),
+    /// 進捗を表示する
+    Status(commands::status::StatusArgs),
+    /// TUI ダッシュボードを起動する
+    Monitor(commands::monitor::MonitorArgs),
+    /// Spec のタスクループを実行する
+    Loop(commands::loop_cmd::LoopArgs),
+    /// 状態の保存・復元
+    Persist(commands::persist::PersistArgs),
+    /// 品質ゲートをチェックする
+    Gate(commands::gate::GateArgs),
+    /// PR 作成・マージへ進む
+    Integrate(commands::integrate::IntegrateArgs),
+    /// 振り返りを記録する
+    Retro(commands::retro::RetroArgs),
+    /// Spec/Task/Session の整合性をチェックする
+    Doctor(commands::doctor::DoctorArgs),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::Init(args) => commands::init::execute(args),
+        Commands::Spec(args) => commands::spec::execute(args),
+        Commands::SpecClone(args) => commands::spec::execute_clone(args),
+        Commands::Tasks(args) => commands::tasks::execute(args),
+        Commands::Orchestrate(args) => commands::orchestrate::execute(args).await,
+        Commands::Status(args) => commands::status::execute(args),
+        Commands::Monitor(args) => commands::monitor::execute(args),
+        Commands::Loop(args) => commands::loop_cmd::execute(args).await,
+        Commands::Persist(args) => commands::persist::execute(args),
+        Commands::Gate(args) => commands::gate::execute(args),
+        Commands::Integrate(args) => commands::integrate::execute(args),
+        Commands::Retro(args) => commands::retro::execute(args),
+        Commands::Doctor(args) => commands::doctor::execute(args),
+    }
+}
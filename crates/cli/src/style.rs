@@ -0,0 +1,131 @@
+//! CLI 出力のスタイルトークン解決。
+//!
+//! `config/styles.toml` のスタイル定義と `.claude/styles/.current-style` の
+//! 現在スタイルを読み、コマンド出力のプレフィックス（完了：/成功しました：
+//! など）を差し替える。スタイルが読めない場合はデフォルト文言を使う。
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// 現在適用中のスタイルのトークン集合。
+#[derive(Debug, Clone, Default)]
+pub struct StyleConfig {
+    tokens: HashMap<String, String>,
+}
+
+impl StyleConfig {
+    /// 規定のパス（`config/styles.toml` と `.claude/styles/.current-style`）
+    /// から読み込む。どちらかが無ければ空（= 全てデフォルト文言）。
+    pub fn load() -> StyleConfig {
+        Self::load_from(
+            Path::new("config/styles.toml"),
+            Path::new(".claude/styles/.current-style"),
+        )
+    }
+
+    pub fn load_from(styles_path: &Path, current_style_path: &Path) -> StyleConfig {
+        let Ok(current) = std::fs::read_to_string(current_style_path) else {
+            return StyleConfig::default();
+        };
+        let Ok(content) = std::fs::read_to_string(styles_path) else {
+            return StyleConfig::default();
+        };
+        let Ok(value) = content.parse::<toml::Value>() else {
+            return StyleConfig::default();
+        };
+
+        let mut tokens = HashMap::new();
+        if let Some(style) = value
+            .get("styles")
+            .and_then(|s| s.get(current.trim()))
+            .and_then(|s| s.as_table())
+        {
+            for (key, v) in style {
+                if let Some(text) = v.as_str() {
+                    tokens.insert(key.clone(), text.to_string());
+                }
+            }
+        }
+        StyleConfig { tokens }
+    }
+
+    /// トークンを返す。未定義なら `None`。
+    pub fn token(&self, key: &str) -> Option<&str> {
+        self.tokens.get(key).map(|s| s.as_str())
+    }
+}
+
+fn current() -> &'static StyleConfig {
+    static STYLE: OnceLock<StyleConfig> = OnceLock::new();
+    STYLE.get_or_init(StyleConfig::load)
+}
+
+/// スタイルトークンをプレフィックスにしてメッセージを出力する。
+///
+/// 現在スタイルに `key` のトークンがあればそれを、無ければ `default` を
+/// プレフィックスとして使う。
+pub fn styled_println(key: &str, default: &str, message: &str) {
+    println!("{}", styled_line(current(), key, default, message));
+}
+
+/// `styled_println` の整形部分。テストから直接検証できるよう分離。
+pub(crate) fn styled_line(
+    style: &StyleConfig,
+    key: &str,
+    default: &str,
+    message: &str,
+) -> String {
+    format!("{}{}", style.token(key).unwrap_or(default), message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STYLES_TOML: &str = r#"
+[styles.standard]
+completion = "完了："
+error = "エラー："
+
+[styles.sage]
+completion = "成功しました："
+error = "否："
+"#;
+
+    fn load_with_current(current: &str) -> StyleConfig {
+        let dir = tempfile::tempdir().unwrap();
+        let styles = dir.path().join("styles.toml");
+        let marker = dir.path().join(".current-style");
+        std::fs::write(&styles, STYLES_TOML).unwrap();
+        std::fs::write(&marker, current).unwrap();
+        StyleConfig::load_from(&styles, &marker)
+    }
+
+    #[test]
+    fn test_output_follows_current_style() {
+        let standard = load_with_current("standard");
+        assert_eq!(
+            styled_line(&standard, "completion", "完了：", "保存しました"),
+            "完了：保存しました"
+        );
+
+        let sage = load_with_current("sage");
+        assert_eq!(
+            styled_line(&sage, "completion", "完了：", "保存しました"),
+            "成功しました：保存しました"
+        );
+    }
+
+    #[test]
+    fn test_missing_style_falls_back_to_default() {
+        let unknown = load_with_current("nonexistent");
+        assert_eq!(
+            styled_line(&unknown, "completion", "完了：", "X"),
+            "完了：X"
+        );
+
+        let empty = StyleConfig::default();
+        assert_eq!(styled_line(&empty, "error", "エラー：", "Y"), "エラー：Y");
+    }
+}
@@ -0,0 +1,11 @@
+//! エンティティ — 識別子を持ち、ライフサイクルを通じて同一性が保たれるオブジェクト。
+
+pub mod session;
+pub mod spec;
+pub mod task;
+pub mod workflow;
+
+pub use session::Session;
+pub use spec::{AcceptanceCriterion, Spec};
+pub use task::{StatusChange, Task};
+pub use workflow::Workflow;
@@ -0,0 +1,75 @@
+use crate::value_objects::{Phase, SessionId, SessionStatus, SpecId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// セッションエンティティ。1つの Spec を1フェーズ進める子 Claude Code セッションに対応する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub id: SessionId,
+    pub spec_id: SpecId,
+    pub phase: Phase,
+    pub status: SessionStatus,
+    /// コンテキスト使用率（0.0〜100.0）。70% ルールの監視に使う。
+    pub context_usage: f64,
+    /// SLA 監視用のデッドライン。超過しそうな Running セッションは
+    /// 監視ループが警告イベントを発火する。
+    #[serde(default)]
+    pub deadline: Option<DateTime<Utc>>,
+    /// 排他したい外部リソース（共有 DB・特定ポートなど）の識別子。
+    /// 同じロックを要求するセッションは同時に起動されない。
+    #[serde(default)]
+    pub resource_locks: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn new(spec_id: SpecId, phase: Phase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: SessionId::new(),
+            spec_id,
+            phase,
+            status: SessionStatus::Pending,
+            context_usage: 0.0,
+            deadline: None,
+            resource_locks: Vec::new(),
+            started_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// デッドラインを設定する。
+    pub fn with_deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// 排他リソースロックを設定する。
+    pub fn with_resource_locks(mut self, locks: Vec<String>) -> Self {
+        self.resource_locks = locks;
+        self
+    }
+
+    /// ステータスを変更する。
+    pub fn change_status(&mut self, next: SessionStatus) {
+        self.status = next;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_session_is_pending() {
+        let session = Session::new(SpecId::from("SPEC-001"), Phase::Tdd);
+        assert_eq!(session.status, SessionStatus::Pending);
+        assert!(!session.is_terminal());
+    }
+}
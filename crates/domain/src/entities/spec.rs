@@ -0,0 +1,76 @@
+use crate::value_objects::{Priority, SpecId, SpecLifecycle};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 受け入れ基準。SPEC フェーズでテスト可能な形式で記述される。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptanceCriterion {
+    /// Spec 内で一意な基準 ID（例: `AC-01`）。
+    pub id: String,
+    pub description: String,
+    pub priority: Priority,
+}
+
+/// 仕様書エンティティ。ワークフローの起点となる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Spec {
+    pub id: SpecId,
+    pub name: String,
+    pub description: String,
+    pub acceptance_criteria: Vec<AcceptanceCriterion>,
+    /// ライフサイクル状態。既存の JSON には無いフィールドなので
+    /// 欠落時は Active とみなす。
+    #[serde(default)]
+    pub lifecycle: SpecLifecycle,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Spec {
+    pub fn new(id: SpecId, name: impl Into<String>, description: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            name: name.into(),
+            description: description.into(),
+            acceptance_criteria: Vec::new(),
+            lifecycle: SpecLifecycle::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 受け入れ基準を追加する。
+    pub fn add_criterion(&mut self, criterion: AcceptanceCriterion) {
+        self.acceptance_criteria.push(criterion);
+        self.updated_at = Utc::now();
+    }
+
+    /// ライフサイクル状態を変更する。
+    pub fn change_lifecycle(&mut self, lifecycle: SpecLifecycle) {
+        self.lifecycle = lifecycle;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.lifecycle == SpecLifecycle::Active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_criterion_updates_timestamp() {
+        let mut spec = Spec::new(SpecId::from("SPEC-001"), "auth", "JWT 認証機能");
+        let before = spec.updated_at;
+        spec.add_criterion(AcceptanceCriterion {
+            id: "AC-01".to_string(),
+            description: "ログインできる".to_string(),
+            priority: Priority::Must,
+        });
+        assert_eq!(spec.acceptance_criteria.len(), 1);
+        assert!(spec.updated_at >= before);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::value_objects::{Complexity, Priority, SpecId, Status, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ステータス変更の監査レコード。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub from: Status,
+    pub to: Status,
+    pub at: DateTime<Utc>,
+}
+
+/// タスクエンティティ。Spec を分割した実行単位。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub id: TaskId,
+    pub spec_id: SpecId,
+    pub name: String,
+    pub description: String,
+    pub status: Status,
+    pub priority: Priority,
+    pub complexity: Complexity,
+    /// 同一 Spec 内で先に完了している必要があるタスク。
+    pub depends_on: Vec<TaskId>,
+    /// このタスクが満たす受け入れ基準（Spec の AcceptanceCriterion.id）。
+    /// 既存の JSON には無いフィールドなので欠落時は空とみなす。
+    #[serde(default)]
+    pub satisfies_criteria: Vec<String>,
+    /// ステータス変更の履歴（いつ・何から何へ変わったか）。
+    /// 既存の JSON には無いフィールドなので欠落時は空とみなす。
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Task {
+    pub fn new(
+        id: TaskId,
+        spec_id: SpecId,
+        name: impl Into<String>,
+        priority: Priority,
+        complexity: Complexity,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            spec_id,
+            name: name.into(),
+            description: String::new(),
+            status: Status::Pending,
+            priority,
+            complexity,
+            depends_on: Vec::new(),
+            satisfies_criteria: Vec::new(),
+            status_history: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// ステータスを変更する。変更は `status_history` に監査記録として残る。
+    pub fn change_status(&mut self, next: Status) {
+        let now = Utc::now();
+        self.status_history.push(StatusChange {
+            from: self.status,
+            to: next,
+            at: now,
+        });
+        self.status = next;
+        self.updated_at = now;
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.status == Status::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_task() -> Task {
+        Task::new(
+            TaskId::from("SPEC-001-T01"),
+            SpecId::from("SPEC-001"),
+            "implement login",
+            Priority::Must,
+            Complexity::Medium,
+        )
+    }
+
+    #[test]
+    fn test_new_task_is_pending() {
+        let task = sample_task();
+        assert_eq!(task.status, Status::Pending);
+        assert!(!task.is_completed());
+    }
+
+    #[test]
+    fn test_change_status() {
+        let mut task = sample_task();
+        task.change_status(Status::Completed);
+        assert!(task.is_completed());
+    }
+
+    #[test]
+    fn test_change_status_records_history() {
+        let mut task = sample_task();
+        task.change_status(Status::InProgress);
+        task.change_status(Status::Completed);
+
+        assert_eq!(task.status_history.len(), 2);
+        assert_eq!(task.status_history[0].from, Status::Pending);
+        assert_eq!(task.status_history[0].to, Status::InProgress);
+        assert_eq!(task.status_history[1].to, Status::Completed);
+    }
+
+    #[test]
+    fn test_history_survives_serialization_roundtrip() {
+        let mut task = sample_task();
+        task.change_status(Status::InProgress);
+
+        let json = serde_json::to_string(&task).unwrap();
+        let parsed: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.status_history, task.status_history);
+    }
+
+    #[test]
+    fn test_history_defaults_to_empty_for_old_json() {
+        // status_history フィールドの無い既存フォーマットの JSON
+        let json = r#"{"id":"T01","spec_id":"SPEC-001","name":"t","description":"","status":"Pending","priority":"Must","complexity":"Small","depends_on":[],"created_at":"2026-09-01T00:00:00Z","updated_at":"2026-09-01T00:00:00Z"}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert!(task.status_history.is_empty());
+    }
+}
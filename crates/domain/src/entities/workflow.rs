@@ -0,0 +1,63 @@
+use crate::value_objects::{Phase, SpecId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// フェーズ遷移の記録。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseTransitionRecord {
+    pub from: Phase,
+    pub to: Phase,
+    pub at: DateTime<Utc>,
+}
+
+/// ワークフローエンティティ。Spec ごとのフェーズ進行を追跡する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub spec_id: SpecId,
+    pub current_phase: Phase,
+    pub history: Vec<PhaseTransitionRecord>,
+}
+
+impl Workflow {
+    pub fn new(spec_id: SpecId) -> Self {
+        Self {
+            spec_id,
+            current_phase: Phase::Spec,
+            history: Vec::new(),
+        }
+    }
+
+    /// 次のフェーズへ進める。遷移を記録し、新しいフェーズを返す。
+    /// 最終フェーズでは `None`。
+    pub fn advance(&mut self) -> Option<Phase> {
+        let next = self.current_phase.next_phase()?;
+        self.history.push(PhaseTransitionRecord {
+            from: self.current_phase,
+            to: next,
+            at: Utc::now(),
+        });
+        self.current_phase = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_records_history() {
+        let mut wf = Workflow::new(SpecId::from("SPEC-001"));
+        assert_eq!(wf.advance(), Some(Phase::Tasks));
+        assert_eq!(wf.history.len(), 1);
+        assert_eq!(wf.current_phase, Phase::Tasks);
+    }
+
+    #[test]
+    fn test_advance_stops_at_merge() {
+        let mut wf = Workflow::new(SpecId::from("SPEC-001"));
+        while wf.advance().is_some() {}
+        assert_eq!(wf.current_phase, Phase::Merge);
+        assert_eq!(wf.history.len(), 5);
+    }
+}
@@ -0,0 +1,9 @@
+//! ドメインイベント — エンティティの状態変化を表す。
+
+pub mod session_events;
+pub mod spec_events;
+pub mod task_events;
+
+pub use session_events::SessionEvent;
+pub use spec_events::SpecEvent;
+pub use task_events::TaskEvent;
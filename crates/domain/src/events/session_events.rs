@@ -0,0 +1,14 @@
+use crate::value_objects::{SessionId, SessionStatus};
+use serde::{Deserialize, Serialize};
+
+/// Session に関するドメインイベント。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SessionEvent {
+    Started(SessionId),
+    StatusChanged {
+        id: SessionId,
+        from: SessionStatus,
+        to: SessionStatus,
+    },
+    Finished(SessionId),
+}
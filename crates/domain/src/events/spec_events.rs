@@ -0,0 +1,10 @@
+use crate::value_objects::SpecId;
+use serde::{Deserialize, Serialize};
+
+/// Spec に関するドメインイベント。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SpecEvent {
+    Created(SpecId),
+    Updated(SpecId),
+    Deleted(SpecId),
+}
@@ -0,0 +1,14 @@
+use crate::value_objects::{Status, TaskId};
+use serde::{Deserialize, Serialize};
+
+/// Task に関するドメインイベント。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TaskEvent {
+    Created(TaskId),
+    StatusChanged {
+        id: TaskId,
+        from: Status,
+        to: Status,
+    },
+    Deleted(TaskId),
+}
@@ -0,0 +1,11 @@
+//! ドメイン層 — 外部サービスに依存しない純粋なビジネスロジック。
+//!
+//! エンティティ・値オブジェクト・ドメインイベント・リポジトリトレイト・
+//! ドメインサービスを提供する。上位層（application / infrastructure）から
+//! 参照されるが、この層自身は他の層を参照しない。
+
+pub mod entities;
+pub mod events;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
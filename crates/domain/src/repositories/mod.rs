@@ -0,0 +1,25 @@
+//! リポジトリトレイト — 永続化の抽象。実装は infrastructure 層が提供する。
+
+pub mod session_repository;
+pub mod spec_repository;
+pub mod task_repository;
+
+pub use session_repository::SessionRepository;
+pub use spec_repository::SpecRepository;
+pub use task_repository::TaskRepository;
+
+/// リポジトリ操作のエラー。
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serialization error: {0}")]
+    Serialization(String),
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("validation failed: {0}")]
+    Validation(String),
+}
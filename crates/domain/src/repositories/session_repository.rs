@@ -0,0 +1,18 @@
+use crate::entities::Session;
+use crate::repositories::RepositoryError;
+use crate::value_objects::SessionId;
+
+/// Session の永続化を抽象するリポジトリトレイト。
+pub trait SessionRepository {
+    fn save(&self, session: &Session) -> Result<(), RepositoryError>;
+
+    /// ID で検索する。存在しなければ `Ok(None)`。
+    fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, RepositoryError>;
+
+    fn find_all(&self) -> Result<Vec<Session>, RepositoryError>;
+
+    /// 終端状態に達していないセッションを返す。
+    fn find_active(&self) -> Result<Vec<Session>, RepositoryError>;
+
+    fn delete(&self, id: &SessionId) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,15 @@
+use crate::entities::Spec;
+use crate::repositories::RepositoryError;
+use crate::value_objects::SpecId;
+
+/// Spec の永続化を抽象するリポジトリトレイト。
+pub trait SpecRepository {
+    fn save(&self, spec: &Spec) -> Result<(), RepositoryError>;
+
+    /// ID で検索する。存在しなければ `Ok(None)`。
+    fn find_by_id(&self, id: &SpecId) -> Result<Option<Spec>, RepositoryError>;
+
+    fn find_all(&self) -> Result<Vec<Spec>, RepositoryError>;
+
+    fn delete(&self, id: &SpecId) -> Result<(), RepositoryError>;
+}
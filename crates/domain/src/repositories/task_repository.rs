@@ -0,0 +1,37 @@
+use crate::entities::Task;
+use crate::repositories::RepositoryError;
+use crate::value_objects::{SpecId, Status, TaskId};
+use std::collections::HashMap;
+
+/// Task の永続化を抽象するリポジトリトレイト。
+pub trait TaskRepository {
+    fn save(&self, task: &Task) -> Result<(), RepositoryError>;
+
+    /// ID で検索する。存在しなければ `Ok(None)`。
+    fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, RepositoryError>;
+
+    /// 指定 Spec に属する全タスクを返す。
+    fn find_by_spec_id(&self, spec_id: &SpecId) -> Result<Vec<Task>, RepositoryError>;
+
+    /// 全 Spec 横断で全タスクを返す。
+    ///
+    /// `doctor` コマンドや全体集計で使う。
+    fn find_all(&self) -> Result<Vec<Task>, RepositoryError>;
+
+    fn delete(&self, id: &TaskId) -> Result<(), RepositoryError>;
+
+    /// Spec 内のタスクをステータス別に集計する。
+    ///
+    /// 進捗表示やサマリで全タスクを都度読んで数えずに済むよう、
+    /// リポジトリ側で集計して返す。
+    fn count_by_status(
+        &self,
+        spec_id: &SpecId,
+    ) -> Result<HashMap<Status, usize>, RepositoryError> {
+        let mut counts = HashMap::new();
+        for task in self.find_by_spec_id(spec_id)? {
+            *counts.entry(task.status).or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+}
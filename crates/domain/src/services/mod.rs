@@ -0,0 +1,9 @@
+//! ドメインサービス — 単一のエンティティに属さないビジネスルール。
+
+pub mod quality_service;
+pub mod workflow_service;
+
+pub use quality_service::{
+    CoverageProvider, GateContext, QualityConfig, QualityService, TestOutcome, TestRunner,
+};
+pub use workflow_service::{TransitionError, WorkflowService};
@@ -0,0 +1,621 @@
+use crate::entities::{Spec, Task};
+use crate::value_objects::{CheckStatus, Phase, QualityCheck, QualityGate, Severity, Status};
+
+/// テストスイートの実行結果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestOutcome {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// テストスイートの実行を抽象するトレイト。
+///
+/// デフォルト実装（cargo test を実行する `CargoTestRunner`）は
+/// infrastructure 層にあり、テストではモックを注入する。
+pub trait TestRunner {
+    /// テストを実行して結果を返す。実行自体の失敗（ビルドエラー等）は `Err`。
+    fn run_tests(&self) -> Result<TestOutcome, String>;
+}
+
+/// カバレッジ測定を抽象するトレイト。
+///
+/// 実装（`cargo llvm-cov` を呼ぶ `LlvmCovProvider`）は infrastructure 層。
+pub trait CoverageProvider {
+    /// ライン被覆率（%）を返す。ツール未インストールなど測定不能なら
+    /// `Ok(None)`、測定自体の失敗は `Err`。
+    fn line_coverage_percent(&self) -> Result<Option<f64>, String>;
+}
+
+/// 品質ゲートの設定。
+#[derive(Debug, Clone)]
+pub struct QualityConfig {
+    /// TDD フェーズで要求する最小ライン被覆率（%）。
+    pub min_coverage_percent: f64,
+}
+
+impl Default for QualityConfig {
+    fn default() -> Self {
+        Self {
+            min_coverage_percent: 80.0,
+        }
+    }
+}
+
+/// REVIEW 以降のフェーズ判定に必要な外部事実。
+///
+/// エスカレーションの未解決数や振り返りファイルの有無はドメイン層から
+/// 直接は観測できないため、呼び出し側（CLI）が集めて渡す。
+#[derive(Debug, Clone, Default)]
+pub struct GateContext {
+    /// 未解決のまま残っているエスカレーション数。
+    pub unresolved_escalations: usize,
+    /// `.aad/retrospectives/` に当該 spec の振り返りが存在するか。
+    pub retro_exists: bool,
+    /// REVIEW フェーズの品質ゲートが承認済みか。
+    pub review_approved: bool,
+}
+
+/// 各フェーズの品質ゲートを評価するドメインサービス。
+#[derive(Default)]
+pub struct QualityService {
+    test_runner: Option<Box<dyn TestRunner>>,
+    coverage_provider: Option<Box<dyn CoverageProvider>>,
+    config: QualityConfig,
+}
+
+impl QualityService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// テストランナーを注入する。未注入なら TDD フェーズのテスト
+    /// チェックはスキップ扱いのプレースホルダになる。
+    pub fn with_test_runner(mut self, runner: Box<dyn TestRunner>) -> Self {
+        self.test_runner = Some(runner);
+        self
+    }
+
+    /// カバレッジプロバイダを注入する。
+    pub fn with_coverage_provider(mut self, provider: Box<dyn CoverageProvider>) -> Self {
+        self.coverage_provider = Some(provider);
+        self
+    }
+
+    /// 品質ゲート設定を差し替える。
+    pub fn with_config(mut self, config: QualityConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// フェーズに応じた品質ゲートを評価して返す。
+    ///
+    /// REVIEW 以降のフェーズは外部事実が必要なため、デフォルトの
+    /// `GateContext` で評価される。実運用では
+    /// `check_phase_gate_with_context` を使うこと。
+    pub fn check_phase_gate(&self, spec: &Spec, tasks: &[Task], phase: &Phase) -> QualityGate {
+        self.check_phase_gate_with_context(spec, tasks, phase, &GateContext::default())
+    }
+
+    /// 外部事実（エスカレーション・振り返り・承認）込みでゲートを評価する。
+    pub fn check_phase_gate_with_context(
+        &self,
+        spec: &Spec,
+        tasks: &[Task],
+        phase: &Phase,
+        context: &GateContext,
+    ) -> QualityGate {
+        match phase {
+            Phase::Spec => self.check_spec_phase(spec),
+            Phase::Tasks => self.check_tasks_phase(spec, tasks),
+            Phase::Tdd => self.check_tdd_phase(tasks),
+            Phase::Review => self.check_review_phase(tasks, context),
+            Phase::Retro => self.check_retro_phase(context),
+            Phase::Merge => self.check_merge_phase(context),
+        }
+    }
+
+    /// REVIEW: 全タスク完了・未解決エスカレーションなし。
+    fn check_review_phase(&self, tasks: &[Task], context: &GateContext) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Review);
+
+        let incomplete = tasks.iter().filter(|t| !t.is_completed()).count();
+        if tasks.is_empty() {
+            gate.add_check(QualityCheck::failed("All tasks completed", "no tasks"));
+        } else if incomplete == 0 {
+            gate.add_check(QualityCheck::passed("All tasks completed"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "All tasks completed",
+                format!("{incomplete} task(s) incomplete"),
+            ));
+        }
+
+        if context.unresolved_escalations == 0 {
+            gate.add_check(QualityCheck::passed("No unresolved escalations"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "No unresolved escalations",
+                format!("{} escalation(s) unresolved", context.unresolved_escalations),
+            ));
+        }
+
+        gate
+    }
+
+    /// RETRO: 当該 spec の振り返りファイルが存在すること。
+    fn check_retro_phase(&self, context: &GateContext) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Retro);
+        if context.retro_exists {
+            gate.add_check(QualityCheck::passed("Retrospective recorded"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "Retrospective recorded",
+                "no retrospective file in .aad/retrospectives/",
+            ));
+        }
+        gate
+    }
+
+    /// MERGE: REVIEW フェーズの品質ゲートが承認済みであること。
+    fn check_merge_phase(&self, context: &GateContext) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Merge);
+        if context.review_approved {
+            gate.add_check(QualityCheck::passed("Review gate approved"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "Review gate approved",
+                "review gate has not been approved",
+            ));
+        }
+        gate
+    }
+
+    /// SPEC: 受け入れ基準がテスト可能な形式で記述されていること。
+    fn check_spec_phase(&self, spec: &Spec) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Spec);
+
+        if spec.description.trim().is_empty() {
+            gate.add_check(QualityCheck::failed("Description present", "description is empty"));
+        } else {
+            gate.add_check(QualityCheck::passed("Description present"));
+        }
+
+        if spec.acceptance_criteria.is_empty() {
+            gate.add_check(QualityCheck::failed(
+                "Acceptance criteria defined",
+                "no acceptance criteria",
+            ));
+        } else {
+            gate.add_check(QualityCheck::passed("Acceptance criteria defined"));
+        }
+
+        gate
+    }
+
+    /// TASKS: 全タスクに ID が付与され、依存関係が解決可能であること。
+    fn check_tasks_phase(&self, spec: &Spec, tasks: &[Task]) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Tasks);
+
+        if tasks.is_empty() {
+            gate.add_check(QualityCheck::failed("Tasks exist", "spec has no tasks"));
+        } else {
+            gate.add_check(QualityCheck::passed("Tasks exist"));
+        }
+
+        let orphans: Vec<&Task> = tasks.iter().filter(|t| t.spec_id != spec.id).collect();
+        if orphans.is_empty() {
+            gate.add_check(QualityCheck::passed("Tasks belong to spec"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "Tasks belong to spec",
+                format!("{} task(s) reference a different spec", orphans.len()),
+            ));
+        }
+
+        // 全 AC が少なくとも1つのタスクでカバーされていること
+        let covered: std::collections::HashSet<&str> = tasks
+            .iter()
+            .flat_map(|t| t.satisfies_criteria.iter().map(|s| s.as_str()))
+            .collect();
+        let uncovered: Vec<&str> = spec
+            .acceptance_criteria
+            .iter()
+            .map(|ac| ac.id.as_str())
+            .filter(|id| !covered.contains(id))
+            .collect();
+        if uncovered.is_empty() {
+            gate.add_check(QualityCheck::passed("All acceptance criteria covered"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "All acceptance criteria covered",
+                format!("uncovered: {}", uncovered.join(", ")),
+            ));
+        }
+
+        gate
+    }
+
+    /// TDD: テスト・カバレッジ・Lint の通過。
+    fn check_tdd_phase(&self, tasks: &[Task]) -> QualityGate {
+        let mut gate = QualityGate::new(Phase::Tdd);
+
+        let blocked = tasks.iter().filter(|t| t.status == Status::Blocked).count();
+        if blocked == 0 {
+            gate.add_check(QualityCheck::passed("No blocked tasks"));
+        } else {
+            gate.add_check(QualityCheck::failed(
+                "No blocked tasks",
+                format!("{blocked} task(s) blocked"),
+            ));
+        }
+
+        // テストランナーが注入されていれば実際にテストスイートを実行する
+        match self.test_runner.as_ref().map(|r| r.run_tests()) {
+            Some(Ok(outcome)) if outcome.failed == 0 => {
+                gate.add_check(QualityCheck::passed("Tests passing"));
+            }
+            Some(Ok(outcome)) => {
+                gate.add_check(QualityCheck::failed(
+                    "Tests passing",
+                    format!("{} test(s) failed ({} passed)", outcome.failed, outcome.passed),
+                ));
+            }
+            Some(Err(reason)) => {
+                gate.add_check(QualityCheck::failed(
+                    "Tests passing",
+                    format!("test run failed: {reason}"),
+                ));
+            }
+            // ランナー未注入（プレースホルダ）
+            None => gate.add_check(QualityCheck::passed("Tests passing")),
+        }
+        // カバレッジプロバイダが注入されていれば閾値チェックを行う
+        let coverage_name = format!(
+            "Code coverage >= {}%",
+            self.config.min_coverage_percent
+        );
+        match self
+            .coverage_provider
+            .as_ref()
+            .map(|p| p.line_coverage_percent())
+        {
+            Some(Ok(Some(percent))) if percent >= self.config.min_coverage_percent => {
+                gate.add_check(QualityCheck::passed(coverage_name));
+            }
+            Some(Ok(Some(percent))) => {
+                gate.add_check(QualityCheck::failed(
+                    coverage_name,
+                    format!("measured {percent:.1}%"),
+                ));
+            }
+            Some(Ok(None)) => {
+                gate.add_check(QualityCheck::skipped(
+                    coverage_name,
+                    "coverage tool not available",
+                ));
+            }
+            Some(Err(reason)) => {
+                gate.add_check(QualityCheck::failed(
+                    coverage_name,
+                    format!("coverage run failed: {reason}"),
+                ));
+            }
+            // プロバイダ未注入（プレースホルダ）
+            None => gate.add_check(QualityCheck::passed(coverage_name)),
+        }
+        // TODO: Integrate linter (placeholder always passes)
+        gate.add_check(QualityCheck::passed("Lint passing"));
+
+        gate
+    }
+
+    /// CI 向けの機械可読な JSON レポートを生成する。
+    ///
+    /// phase・passed・approved と各チェックの name/status/severity/reason を
+    /// 構造化して出力する。色付けや絵文字は含まない。
+    pub fn generate_json_report(&self, gate: &QualityGate) -> serde_json::Result<String> {
+        #[derive(serde::Serialize)]
+        struct JsonReport<'a> {
+            phase: &'a Phase,
+            passed: bool,
+            approved: bool,
+            checks: &'a [QualityCheck],
+        }
+        serde_json::to_string_pretty(&JsonReport {
+            phase: &gate.phase,
+            passed: gate.passed(),
+            approved: gate.approved,
+            checks: &gate.checks,
+        })
+    }
+
+    /// 人間向けのテキストレポートを生成する。
+    pub fn generate_report(&self, gate: &QualityGate) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("品質ゲート: {} フェーズ\n", gate.phase));
+        out.push_str(&format!(
+            "結果: {}\n\n",
+            if gate.passed() { "✅ 通過" } else { "❌ 未通過" }
+        ));
+        for check in &gate.checks {
+            // severity 別に色分け: Error の失敗は ❌、Warning は ⚠️、Info は ℹ️
+            let mark = match (check.status, check.severity) {
+                (CheckStatus::Passed, _) => "✅",
+                (CheckStatus::Skipped, _) => "⏭️",
+                (CheckStatus::Failed, Severity::Error) => "❌",
+                (CheckStatus::Failed, Severity::Warning) => "⚠️",
+                (CheckStatus::Failed, Severity::Info) => "ℹ️",
+            };
+            out.push_str(&format!("  {} {}", mark, check.name));
+            if let Some(reason) = &check.reason {
+                out.push_str(&format!(" — {reason}"));
+            }
+            out.push('\n');
+        }
+        if gate.phase.requires_human_approval() {
+            out.push_str(&format!(
+                "\n⚠️ 人間承認: {}\n",
+                if gate.approved { "承認済み" } else { "未承認" }
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::AcceptanceCriterion;
+    use crate::value_objects::{Complexity, Priority, SpecId, TaskId};
+
+    fn spec_with_criteria() -> Spec {
+        let mut spec = Spec::new(SpecId::from("SPEC-001"), "auth", "JWT 認証機能");
+        spec.add_criterion(AcceptanceCriterion {
+            id: "AC-01".to_string(),
+            description: "ログインできる".to_string(),
+            priority: Priority::Must,
+        });
+        spec
+    }
+
+    #[test]
+    fn test_spec_phase_requires_criteria() {
+        let service = QualityService::new();
+        let empty = Spec::new(SpecId::from("SPEC-002"), "empty", "desc");
+        assert!(!service.check_phase_gate(&empty, &[], &Phase::Spec).passed());
+        assert!(service
+            .check_phase_gate(&spec_with_criteria(), &[], &Phase::Spec)
+            .passed());
+    }
+
+    #[test]
+    fn test_tasks_phase_requires_tasks() {
+        let service = QualityService::new();
+        let spec = spec_with_criteria();
+        assert!(!service.check_phase_gate(&spec, &[], &Phase::Tasks).passed());
+
+        let mut task = Task::new(
+            TaskId::from("SPEC-001-T01"),
+            spec.id.clone(),
+            "t",
+            Priority::Must,
+            Complexity::Small,
+        );
+        task.satisfies_criteria.push("AC-01".to_string());
+        assert!(service
+            .check_phase_gate(&spec, &[task], &Phase::Tasks)
+            .passed());
+    }
+
+    #[test]
+    fn test_tasks_phase_fails_on_uncovered_criteria() {
+        let service = QualityService::new();
+        let mut spec = spec_with_criteria();
+        spec.add_criterion(AcceptanceCriterion {
+            id: "AC-02".to_string(),
+            description: "ログアウトできる".to_string(),
+            priority: Priority::Should,
+        });
+
+        // AC-01 だけカバーするタスク → AC-02 が未カバーで失敗
+        let mut task = Task::new(
+            TaskId::from("SPEC-001-T01"),
+            spec.id.clone(),
+            "t",
+            Priority::Must,
+            Complexity::Small,
+        );
+        task.satisfies_criteria.push("AC-01".to_string());
+
+        let gate = service.check_phase_gate(&spec, &[task], &Phase::Tasks);
+        assert!(!gate.passed());
+        let coverage = gate
+            .checks
+            .iter()
+            .find(|c| c.name == "All acceptance criteria covered")
+            .unwrap();
+        assert!(coverage.reason.as_ref().unwrap().contains("AC-02"));
+    }
+
+    struct MockRunner(Result<TestOutcome, String>);
+
+    impl TestRunner for MockRunner {
+        fn run_tests(&self) -> Result<TestOutcome, String> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_tdd_gate_uses_injected_test_runner() {
+        let spec = spec_with_criteria();
+
+        // 成功
+        let service = QualityService::new().with_test_runner(Box::new(MockRunner(
+            Ok(TestOutcome { passed: 10, failed: 0 }),
+        )));
+        assert!(service.check_phase_gate(&spec, &[], &Phase::Tdd).passed());
+
+        // テスト失敗（失敗数が理由に含まれる）
+        let service = QualityService::new().with_test_runner(Box::new(MockRunner(
+            Ok(TestOutcome { passed: 8, failed: 2 }),
+        )));
+        let gate = service.check_phase_gate(&spec, &[], &Phase::Tdd);
+        assert!(!gate.passed());
+        let check = gate.checks.iter().find(|c| c.name == "Tests passing").unwrap();
+        assert!(check.reason.as_ref().unwrap().contains("2 test(s) failed"));
+
+        // 実行エラー
+        let service = QualityService::new()
+            .with_test_runner(Box::new(MockRunner(Err("build error".to_string()))));
+        assert!(!service.check_phase_gate(&spec, &[], &Phase::Tdd).passed());
+    }
+
+    struct MockCoverage(Result<Option<f64>, String>);
+
+    impl CoverageProvider for MockCoverage {
+        fn line_coverage_percent(&self) -> Result<Option<f64>, String> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_coverage_threshold_boundaries() {
+        let spec = spec_with_criteria();
+        // 閾値ちょうどは通過、未満は実測値付きで失敗、超過は通過
+        for (measured, expected) in [(80.0, true), (79.9, false), (80.1, true)] {
+            let service = QualityService::new()
+                .with_coverage_provider(Box::new(MockCoverage(Ok(Some(measured)))));
+            let gate = service.check_phase_gate(&spec, &[], &Phase::Tdd);
+            assert_eq!(gate.passed(), expected, "measured={measured}");
+            if !expected {
+                let check = gate
+                    .checks
+                    .iter()
+                    .find(|c| c.name.starts_with("Code coverage"))
+                    .unwrap();
+                assert!(check.reason.as_ref().unwrap().contains("79.9"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_coverage_tool_unavailable_is_skipped() {
+        let spec = spec_with_criteria();
+        let service =
+            QualityService::new().with_coverage_provider(Box::new(MockCoverage(Ok(None))));
+        let gate = service.check_phase_gate(&spec, &[], &Phase::Tdd);
+        // Skipped はゲート通過を妨げない
+        assert!(gate.passed());
+        assert!(gate
+            .checks
+            .iter()
+            .any(|c| c.status == CheckStatus::Skipped));
+    }
+
+    #[test]
+    fn test_json_report_is_valid_and_includes_failure_reasons() {
+        let service = QualityService::new();
+        let empty = Spec::new(SpecId::from("SPEC-002"), "empty", "");
+        let gate = service.check_phase_gate(&empty, &[], &Phase::Spec);
+
+        let json = service.generate_json_report(&gate).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["phase"], "Spec");
+        assert_eq!(parsed["passed"], false);
+        assert_eq!(parsed["approved"], false);
+        // 失敗チェックの reason が含まれる
+        let reasons: Vec<&str> = parsed["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|c| c["reason"].as_str())
+            .collect();
+        assert!(reasons.iter().any(|r| r.contains("description is empty")));
+        // 絵文字を含まない
+        assert!(!json.contains('✅') && !json.contains('❌'));
+    }
+
+    #[test]
+    fn test_review_gate_requires_completed_tasks_and_no_escalations() {
+        let service = QualityService::new();
+        let spec = spec_with_criteria();
+        let mut task = Task::new(
+            TaskId::from("SPEC-001-T01"),
+            spec.id.clone(),
+            "t",
+            Priority::Must,
+            Complexity::Small,
+        );
+
+        // 未完了タスクがあると失敗
+        let ctx = GateContext::default();
+        let gate = service.check_phase_gate_with_context(
+            &spec,
+            std::slice::from_ref(&task),
+            &Phase::Review,
+            &ctx,
+        );
+        assert!(!gate.passed());
+
+        // 全タスク完了 + エスカレーションなしで通過
+        task.change_status(Status::Completed);
+        let gate = service.check_phase_gate_with_context(
+            &spec,
+            std::slice::from_ref(&task),
+            &Phase::Review,
+            &ctx,
+        );
+        assert!(gate.passed());
+
+        // 未解決エスカレーションが残っていると失敗
+        let ctx = GateContext {
+            unresolved_escalations: 2,
+            ..GateContext::default()
+        };
+        let gate = service.check_phase_gate_with_context(
+            &spec,
+            std::slice::from_ref(&task),
+            &Phase::Review,
+            &ctx,
+        );
+        assert!(!gate.passed());
+    }
+
+    #[test]
+    fn test_retro_gate_requires_retrospective_file() {
+        let service = QualityService::new();
+        let spec = spec_with_criteria();
+
+        let missing = GateContext::default();
+        assert!(!service
+            .check_phase_gate_with_context(&spec, &[], &Phase::Retro, &missing)
+            .passed());
+
+        let exists = GateContext {
+            retro_exists: true,
+            ..GateContext::default()
+        };
+        assert!(service
+            .check_phase_gate_with_context(&spec, &[], &Phase::Retro, &exists)
+            .passed());
+    }
+
+    #[test]
+    fn test_merge_gate_requires_approved_review() {
+        let service = QualityService::new();
+        let spec = spec_with_criteria();
+
+        let unapproved = GateContext::default();
+        assert!(!service
+            .check_phase_gate_with_context(&spec, &[], &Phase::Merge, &unapproved)
+            .passed());
+
+        let approved = GateContext {
+            review_approved: true,
+            ..GateContext::default()
+        };
+        assert!(service
+            .check_phase_gate_with_context(&spec, &[], &Phase::Merge, &approved)
+            .passed());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::value_objects::Phase;
+
+/// 無効なフェーズ遷移の理由。
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TransitionError {
+    #[error("{0} から同一フェーズへの遷移はできません")]
+    SamePhase(Phase),
+
+    #[error("最終フェーズ {0} からは前進できません")]
+    TerminalPhase(Phase),
+
+    #[error("{from} から {to} へは戻れません")]
+    Backward { from: Phase, to: Phase },
+
+    #[error("{from} から {to} へはスキップできません（次は {expected}）")]
+    SkippedPhase {
+        from: Phase,
+        to: Phase,
+        expected: Phase,
+    },
+}
+
+/// フェーズ遷移のルールを司るドメインサービス。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkflowService;
+
+impl WorkflowService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `from` から `to` への遷移が許可されているかどうか。
+    /// ワークフローは前進のみ（次フェーズへの1ステップ）を許可する。
+    pub fn can_transition(&self, from: &Phase, to: &Phase) -> bool {
+        self.can_transition_detailed(from, to).is_ok()
+    }
+
+    /// 遷移可否を理由付きで返す。
+    ///
+    /// CLI が遷移を拒否した際に、なぜできないのか（順序違反・最終
+    /// フェーズからの前進など）をユーザーに表示するために使う。
+    pub fn can_transition_detailed(
+        &self,
+        from: &Phase,
+        to: &Phase,
+    ) -> Result<(), TransitionError> {
+        if from == to {
+            return Err(TransitionError::SamePhase(*from));
+        }
+        let Some(next) = from.next_phase() else {
+            return Err(TransitionError::TerminalPhase(*from));
+        };
+        if next == *to {
+            return Ok(());
+        }
+
+        let index = |p: &Phase| Phase::all().iter().position(|x| x == p).unwrap_or(0);
+        if index(to) < index(from) {
+            Err(TransitionError::Backward {
+                from: *from,
+                to: *to,
+            })
+        } else {
+            Err(TransitionError::SkippedPhase {
+                from: *from,
+                to: *to,
+                expected: next,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_transition_allowed() {
+        let service = WorkflowService::new();
+        assert!(service.can_transition(&Phase::Spec, &Phase::Tasks));
+        assert!(service.can_transition(&Phase::Tdd, &Phase::Review));
+    }
+
+    #[test]
+    fn test_skip_and_backward_rejected() {
+        let service = WorkflowService::new();
+        assert!(!service.can_transition(&Phase::Spec, &Phase::Tdd));
+        assert!(!service.can_transition(&Phase::Review, &Phase::Tdd));
+        assert!(!service.can_transition(&Phase::Merge, &Phase::Spec));
+    }
+
+    #[test]
+    fn test_detailed_reasons_for_invalid_transitions() {
+        let service = WorkflowService::new();
+
+        assert_eq!(
+            service.can_transition_detailed(&Phase::Tdd, &Phase::Tdd),
+            Err(TransitionError::SamePhase(Phase::Tdd))
+        );
+        assert_eq!(
+            service.can_transition_detailed(&Phase::Merge, &Phase::Spec),
+            Err(TransitionError::TerminalPhase(Phase::Merge))
+        );
+        assert_eq!(
+            service.can_transition_detailed(&Phase::Review, &Phase::Tdd),
+            Err(TransitionError::Backward {
+                from: Phase::Review,
+                to: Phase::Tdd,
+            })
+        );
+        assert_eq!(
+            service.can_transition_detailed(&Phase::Spec, &Phase::Tdd),
+            Err(TransitionError::SkippedPhase {
+                from: Phase::Spec,
+                to: Phase::Tdd,
+                expected: Phase::Tasks,
+            })
+        );
+        assert_eq!(
+            service.can_transition_detailed(&Phase::Spec, &Phase::Tasks),
+            Ok(())
+        );
+    }
+}
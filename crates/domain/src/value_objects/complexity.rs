@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// タスクの見積もり規模。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Complexity {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Complexity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Complexity::Small => "small",
+            Complexity::Medium => "medium",
+            Complexity::Large => "large",
+        }
+    }
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Complexity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "small" | "s" => Ok(Complexity::Small),
+            "medium" | "m" => Ok(Complexity::Medium),
+            "large" | "l" => Ok(Complexity::Large),
+            other => Err(format!("unknown complexity: {other}")),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! 値オブジェクト — 同一性ではなく値そのもので比較される不変オブジェクト。
+
+pub mod complexity;
+pub mod phase;
+pub mod priority;
+pub mod quality_gate;
+pub mod session_id;
+pub mod spec_id;
+pub mod spec_lifecycle;
+pub mod status;
+pub mod task_id;
+
+pub use complexity::Complexity;
+pub use phase::Phase;
+pub use priority::Priority;
+pub use quality_gate::{CheckStatus, QualityCheck, QualityGate, Severity};
+pub use session_id::SessionId;
+pub use spec_id::SpecId;
+pub use spec_lifecycle::SpecLifecycle;
+pub use status::{SessionStatus, Status};
+pub use task_id::TaskId;
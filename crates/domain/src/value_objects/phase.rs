@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 6フェーズワークフロー（SPEC → TASKS → TDD → REVIEW → RETRO → MERGE）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Phase {
+    /// 仕様書作成
+    Spec,
+    /// タスク分割
+    Tasks,
+    /// テスト駆動開発
+    Tdd,
+    /// コードレビュー
+    Review,
+    /// 振り返り
+    Retro,
+    /// 統合
+    Merge,
+}
+
+impl Phase {
+    /// 全フェーズをワークフロー順で返す。
+    pub fn all() -> [Phase; 6] {
+        [
+            Phase::Spec,
+            Phase::Tasks,
+            Phase::Tdd,
+            Phase::Review,
+            Phase::Retro,
+            Phase::Merge,
+        ]
+    }
+
+    /// 次のフェーズ。最終フェーズ（Merge）では `None`。
+    pub fn next_phase(&self) -> Option<Phase> {
+        match self {
+            Phase::Spec => Some(Phase::Tasks),
+            Phase::Tasks => Some(Phase::Tdd),
+            Phase::Tdd => Some(Phase::Review),
+            Phase::Review => Some(Phase::Retro),
+            Phase::Retro => Some(Phase::Merge),
+            Phase::Merge => None,
+        }
+    }
+
+    /// 前のフェーズ。最初のフェーズ（Spec）では `None`。
+    pub fn previous_phase(&self) -> Option<Phase> {
+        match self {
+            Phase::Spec => None,
+            Phase::Tasks => Some(Phase::Spec),
+            Phase::Tdd => Some(Phase::Tasks),
+            Phase::Review => Some(Phase::Tdd),
+            Phase::Retro => Some(Phase::Review),
+            Phase::Merge => Some(Phase::Retro),
+        }
+    }
+
+    /// このフェーズから遷移可能な次フェーズの一覧。
+    ///
+    /// 前進（`next_phase`）とロールバック（`previous_phase`）を含む。
+    /// TUI の WorkflowView で「次にどのフェーズへ進めるか」の選択肢を
+    /// 提示するために使う。
+    pub fn allowed_transitions(&self) -> Vec<Phase> {
+        [self.previous_phase(), self.next_phase()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// 品質ゲート通過に人間の承認が必要なフェーズかどうか。
+    pub fn requires_human_approval(&self) -> bool {
+        matches!(self, Phase::Spec | Phase::Tasks | Phase::Review)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Spec => "SPEC",
+            Phase::Tasks => "TASKS",
+            Phase::Tdd => "TDD",
+            Phase::Review => "REVIEW",
+            Phase::Retro => "RETRO",
+            Phase::Merge => "MERGE",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_phase_order() {
+        assert_eq!(Phase::Spec.next_phase(), Some(Phase::Tasks));
+        assert_eq!(Phase::Retro.next_phase(), Some(Phase::Merge));
+        assert_eq!(Phase::Merge.next_phase(), None);
+    }
+
+    #[test]
+    fn test_allowed_transitions_cover_all_phases() {
+        // 端（Spec/Merge）は片方向のみ、中間は前後両方
+        assert_eq!(Phase::Spec.allowed_transitions(), vec![Phase::Tasks]);
+        assert_eq!(
+            Phase::Tasks.allowed_transitions(),
+            vec![Phase::Spec, Phase::Tdd]
+        );
+        assert_eq!(
+            Phase::Tdd.allowed_transitions(),
+            vec![Phase::Tasks, Phase::Review]
+        );
+        assert_eq!(
+            Phase::Review.allowed_transitions(),
+            vec![Phase::Tdd, Phase::Retro]
+        );
+        assert_eq!(
+            Phase::Retro.allowed_transitions(),
+            vec![Phase::Review, Phase::Merge]
+        );
+        assert_eq!(Phase::Merge.allowed_transitions(), vec![Phase::Retro]);
+    }
+
+    #[test]
+    fn test_requires_human_approval() {
+        assert!(Phase::Spec.requires_human_approval());
+        assert!(Phase::Review.requires_human_approval());
+        assert!(!Phase::Tdd.requires_human_approval());
+    }
+}
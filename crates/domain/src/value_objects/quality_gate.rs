@@ -0,0 +1,145 @@
+use crate::value_objects::Phase;
+use serde::{Deserialize, Serialize};
+
+/// 個別チェックの結果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    /// 判定材料が得られず実行されなかった（ツール未インストール等）。
+    /// ゲート通過の判定には影響しない。
+    Skipped,
+}
+
+/// チェックの重大度。Warning/Info の失敗はゲート通過を妨げない。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
+
+/// 品質ゲートを構成する1つのチェック項目。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    /// 重大度。既存の JSON には無いフィールドなので欠落時は Error。
+    #[serde(default)]
+    pub severity: Severity,
+    /// 失敗時の理由。
+    pub reason: Option<String>,
+}
+
+impl QualityCheck {
+    pub fn passed(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Passed,
+            severity: Severity::Error,
+            reason: None,
+        }
+    }
+
+    pub fn failed(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Failed,
+            severity: Severity::Error,
+            reason: Some(reason.into()),
+        }
+    }
+
+    /// ツール未インストール等で実行できなかったチェック。
+    pub fn skipped(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Skipped,
+            severity: Severity::Info,
+            reason: Some(reason.into()),
+        }
+    }
+
+    /// 失敗してもゲート通過を妨げない警告チェック。
+    pub fn warning(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Failed,
+            severity: Severity::Warning,
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+/// フェーズ完了を判定する品質ゲート。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityGate {
+    pub phase: Phase,
+    pub checks: Vec<QualityCheck>,
+    /// 人間承認が必要なフェーズで承認済みかどうか。
+    pub approved: bool,
+}
+
+impl QualityGate {
+    pub fn new(phase: Phase) -> Self {
+        Self {
+            phase,
+            checks: Vec::new(),
+            approved: false,
+        }
+    }
+
+    pub fn add_check(&mut self, check: QualityCheck) {
+        self.checks.push(check);
+    }
+
+    /// ゲートが通過しているかどうか。
+    ///
+    /// Error の失敗のみが判定対象。Warning/Info の失敗は通過を妨げない
+    /// （表示はされる）。
+    pub fn passed(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|c| c.status != CheckStatus::Failed || c.severity != Severity::Error)
+    }
+
+    /// 人間承認を記録する。
+    pub fn approve(&mut self) {
+        self.approved = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gate_passed_only_when_all_checks_pass() {
+        let mut gate = QualityGate::new(Phase::Tdd);
+        gate.add_check(QualityCheck::passed("Tests passing"));
+        assert!(gate.passed());
+
+        gate.add_check(QualityCheck::failed("Lint passing", "2 warnings"));
+        assert!(!gate.passed());
+    }
+
+    #[test]
+    fn test_warning_failures_do_not_block_gate() {
+        let mut gate = QualityGate::new(Phase::Tdd);
+        gate.add_check(QualityCheck::passed("Tests passing"));
+        gate.add_check(QualityCheck::warning("Coverage below target", "75%"));
+        assert!(gate.passed());
+
+        gate.add_check(QualityCheck::failed("Lint passing", "2 errors"));
+        assert!(!gate.passed());
+    }
+
+    #[test]
+    fn test_approve() {
+        let mut gate = QualityGate::new(Phase::Review);
+        assert!(!gate.approved);
+        gate.approve();
+        assert!(gate.approved);
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Spec を一意に識別する ID。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SpecId(String);
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+impl SpecId {
+    /// 時刻とプロセス内カウンタから一意な ID を生成する。
+    pub fn new() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        Self(format!(
+            "SPEC-{:08x}",
+            nanos.wrapping_mul(0x9e37_79b9).wrapping_add(n) & 0xffff_ffff
+        ))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for SpecId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for SpecId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for SpecId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl From<String> for SpecId {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_is_unique() {
+        let a = SpecId::new();
+        let b = SpecId::new();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_from_str_roundtrip() {
+        let id = SpecId::from("SPEC-001");
+        assert_eq!(id.as_str(), "SPEC-001");
+        assert_eq!(id.to_string(), "SPEC-001");
+    }
+}
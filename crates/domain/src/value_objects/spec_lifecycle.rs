@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Spec のライフサイクル状態。
+///
+/// `orchestrate` / `status` は Active の Spec だけを実行対象とする。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpecLifecycle {
+    /// 下書き。まだ実行対象ではない。
+    Draft,
+    /// 実行対象。
+    #[default]
+    Active,
+    /// アーカイブ済み。実行対象から外れる。
+    Archived,
+}
+
+impl SpecLifecycle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpecLifecycle::Draft => "draft",
+            SpecLifecycle::Active => "active",
+            SpecLifecycle::Archived => "archived",
+        }
+    }
+}
+
+impl fmt::Display for SpecLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// タスクの進行状態。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Status {
+    Pending,
+    InProgress,
+    Completed,
+    Blocked,
+    Failed,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pending => "pending",
+            Status::InProgress => "in_progress",
+            Status::Completed => "completed",
+            Status::Blocked => "blocked",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// セッション（子 Claude Code プロセス）の状態。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Escalated,
+    /// 外部からの中断（shutdown / cancel_all）。
+    Cancelled,
+}
+
+impl SessionStatus {
+    /// これ以上状態が進まない終端状態かどうか。
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            SessionStatus::Completed | SessionStatus::Failed | SessionStatus::Cancelled
+        )
+    }
+
+    /// `next` への遷移が許可されているかどうか。
+    ///
+    /// 終端状態（Completed/Cancelled）からの遷移と同一状態への遷移は
+    /// 拒否する。Failed からはリトライ（Running）とエスカレーションのみ
+    /// 許可する。
+    pub fn can_transition_to(&self, next: &SessionStatus) -> bool {
+        if self == next {
+            return false;
+        }
+        match self {
+            SessionStatus::Pending => matches!(
+                next,
+                SessionStatus::Running
+                    | SessionStatus::Completed
+                    | SessionStatus::Failed
+                    | SessionStatus::Escalated
+                    | SessionStatus::Cancelled
+            ),
+            SessionStatus::Running => matches!(
+                next,
+                SessionStatus::Completed
+                    | SessionStatus::Failed
+                    | SessionStatus::Escalated
+                    | SessionStatus::Cancelled
+            ),
+            SessionStatus::Escalated => matches!(
+                next,
+                SessionStatus::Running
+                    | SessionStatus::Completed
+                    | SessionStatus::Failed
+                    | SessionStatus::Cancelled
+            ),
+            // リトライとエスカレーションだけが失敗からの出口
+            SessionStatus::Failed => matches!(
+                next,
+                SessionStatus::Running | SessionStatus::Escalated
+            ),
+            // 終端状態からは遷移できない
+            SessionStatus::Completed | SessionStatus::Cancelled => false,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionStatus::Pending => "pending",
+            SessionStatus::Running => "running",
+            SessionStatus::Completed => "completed",
+            SessionStatus::Failed => "failed",
+            SessionStatus::Escalated => "escalated",
+            SessionStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions_from_terminal_states_rejected() {
+        // Completed/Cancelled からはどこへも遷移できない
+        for from in [SessionStatus::Completed, SessionStatus::Cancelled] {
+            for to in [
+                SessionStatus::Pending,
+                SessionStatus::Running,
+                SessionStatus::Failed,
+            ] {
+                assert!(!from.can_transition_to(&to), "{from} -> {to}");
+            }
+        }
+        // Failed はリトライとエスカレーションのみ
+        assert!(SessionStatus::Failed.can_transition_to(&SessionStatus::Running));
+        assert!(SessionStatus::Failed.can_transition_to(&SessionStatus::Escalated));
+        assert!(!SessionStatus::Failed.can_transition_to(&SessionStatus::Completed));
+        // 同一状態への遷移は不可
+        assert!(!SessionStatus::Running.can_transition_to(&SessionStatus::Running));
+    }
+
+    #[test]
+    fn test_terminal_statuses() {
+        assert!(SessionStatus::Completed.is_terminal());
+        assert!(SessionStatus::Failed.is_terminal());
+        assert!(SessionStatus::Cancelled.is_terminal());
+        assert!(!SessionStatus::Running.is_terminal());
+        assert!(!SessionStatus::Escalated.is_terminal());
+    }
+}
@@ -0,0 +1,438 @@
+use crate::persistence::PersistenceError;
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// 件数ベースの保持ポリシーの既定値。
+pub const DEFAULT_KEEP_COUNT: usize = 3;
+
+/// 状態ファイルのバックアップ管理（`.aad/backups/`）。
+///
+/// バックアップは `<元ファイル名>.<タイムスタンプ>.bak` として保存される。
+#[derive(Debug, Clone)]
+pub struct BackupAdapter {
+    backup_dir: PathBuf,
+}
+
+impl BackupAdapter {
+    pub fn new(backup_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            backup_dir: backup_dir.into(),
+        }
+    }
+
+    pub fn backup_dir(&self) -> &Path {
+        &self.backup_dir
+    }
+
+    /// ファイルをバックアップし、バックアップ先のパスを返す。
+    pub fn backup(&self, file_path: &Path) -> Result<PathBuf, PersistenceError> {
+        std::fs::create_dir_all(&self.backup_dir)?;
+        let name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unnamed");
+        let timestamp = Utc::now().format("%Y%m%d-%H%M%S%3f");
+        let backup_path = self.backup_dir.join(format!("{name}.{timestamp}.bak"));
+        std::fs::copy(file_path, &backup_path)?;
+        self.write_checksum(&backup_path)?;
+        Ok(backup_path)
+    }
+
+    /// gzip 圧縮してバックアップし、バックアップ先のパスを返す。
+    ///
+    /// 大きな JSON やログでディスクを圧迫しないよう
+    /// `<name>.<timestamp>.bak.gz` として保存する。
+    pub fn backup_compressed(&self, file_path: &Path) -> Result<PathBuf, PersistenceError> {
+        std::fs::create_dir_all(&self.backup_dir)?;
+        let name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unnamed");
+        let timestamp = Utc::now().format("%Y%m%d-%H%M%S%3f");
+        let backup_path = self.backup_dir.join(format!("{name}.{timestamp}.bak.gz"));
+
+        let content = std::fs::read(file_path)?;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&content)?;
+        std::fs::write(&backup_path, encoder.finish()?)?;
+        self.write_checksum(&backup_path)?;
+        Ok(backup_path)
+    }
+
+    /// バックアップの SHA-256 をサイドカーファイル（`<backup>.sha256`）へ保存する。
+    fn write_checksum(&self, backup_path: &Path) -> Result<(), PersistenceError> {
+        let digest = Self::sha256_hex(backup_path)?;
+        std::fs::write(Self::checksum_path(backup_path), digest)?;
+        Ok(())
+    }
+
+    fn checksum_path(backup_path: &Path) -> PathBuf {
+        let mut os = backup_path.as_os_str().to_owned();
+        os.push(".sha256");
+        PathBuf::from(os)
+    }
+
+    /// チェックサムサイドカーが存在するかどうか。
+    ///
+    /// チェックサム導入以前の古いバックアップはサイドカーを持たないため、
+    /// 呼び出し側が照合付き復元と通常復元を使い分けるのに使う。
+    pub fn checksum_exists(backup_path: &Path) -> bool {
+        Self::checksum_path(backup_path).exists()
+    }
+
+    fn sha256_hex(path: &Path) -> Result<String, PersistenceError> {
+        let content = std::fs::read(path)?;
+        let digest = Sha256::digest(&content);
+        Ok(format!("{digest:x}"))
+    }
+
+    /// チェックサムを照合してから復元する。
+    ///
+    /// サイドカーの SHA-256 と一致しない場合は `ChecksumMismatch` を返し、
+    /// 破損したバックアップでターゲットを上書きしない。
+    pub fn restore_verified(
+        &self,
+        backup_path: &Path,
+        target: &Path,
+    ) -> Result<(), PersistenceError> {
+        if !backup_path.exists() {
+            return Err(PersistenceError::BackupNotFound(
+                backup_path.display().to_string(),
+            ));
+        }
+        let expected = std::fs::read_to_string(Self::checksum_path(backup_path))
+            .map_err(|_| {
+                PersistenceError::BackupNotFound(format!(
+                    "checksum sidecar missing for {}",
+                    backup_path.display()
+                ))
+            })?;
+        let actual = Self::sha256_hex(backup_path)?;
+        if actual != expected.trim() {
+            return Err(PersistenceError::ChecksumMismatch(
+                backup_path.display().to_string(),
+            ));
+        }
+        self.restore(backup_path, target)
+    }
+
+    /// バックアップの内容を読み出す。`.gz` は自動で解凍する。
+    ///
+    /// restore の実体であり、dry-run の差分プレビューにも使う。
+    pub fn read_content(backup_path: &Path) -> Result<Vec<u8>, PersistenceError> {
+        if !backup_path.exists() {
+            return Err(PersistenceError::BackupNotFound(
+                backup_path.display().to_string(),
+            ));
+        }
+        let raw = std::fs::read(backup_path)?;
+        if backup_path.extension().is_some_and(|ext| ext == "gz") {
+            let mut decoder = GzDecoder::new(raw.as_slice());
+            let mut content = Vec::new();
+            decoder.read_to_end(&mut content)?;
+            Ok(content)
+        } else {
+            Ok(raw)
+        }
+    }
+
+    /// バックアップをターゲットへ復元する。`.gz` は自動で解凍する。
+    pub fn restore(&self, backup_path: &Path, target: &Path) -> Result<(), PersistenceError> {
+        let content = Self::read_content(backup_path)?;
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(target, content)?;
+        Ok(())
+    }
+
+    /// バックアップ一覧をファイル名順（= 時刻順）で返す。
+    pub fn list_backups(&self) -> Result<Vec<PathBuf>, PersistenceError> {
+        let mut backups = Vec::new();
+        if !self.backup_dir.exists() {
+            return Ok(backups);
+        }
+        for entry in std::fs::read_dir(&self.backup_dir)? {
+            let path = entry?.path();
+            // 非圧縮（.bak）と圧縮（.bak.gz）の両方を列挙する
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if name.ends_with(".bak") || name.ends_with(".bak.gz") {
+                backups.push(path);
+            }
+        }
+        backups.sort();
+        Ok(backups)
+    }
+
+    /// 元ファイルごとに最新 `keep_count` 件を残して古いバックアップを削除する。
+    /// 削除件数を返す。
+    pub fn cleanup_old_backups(&self, keep_count: usize) -> Result<usize, PersistenceError> {
+        let backups = self.list_backups()?;
+
+        // `<name>.<timestamp>.bak` の <name> ごとにグルーピングする
+        let mut by_name: std::collections::HashMap<String, Vec<&PathBuf>> =
+            std::collections::HashMap::new();
+        for path in &backups {
+            if let Some(name) = Self::original_name(path) {
+                by_name.entry(name).or_default().push(path);
+            }
+        }
+
+        let mut removed = 0;
+        for paths in by_name.values() {
+            if paths.len() <= keep_count {
+                continue;
+            }
+            // list_backups はファイル名順（= 時刻順）なので先頭が最古
+            for path in paths.iter().take(paths.len() - keep_count) {
+                Self::remove_backup(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// 更新時刻が `now - max_age` より古いバックアップを削除する。
+    /// 削除件数を返す。閾値ちょうどのファイルは残る。
+    pub fn cleanup_by_age(&self, max_age: Duration) -> Result<usize, PersistenceError> {
+        let cutoff = SystemTime::now() - max_age;
+        let mut removed = 0;
+        for path in self.list_backups()? {
+            let modified = std::fs::metadata(&path)?.modified()?;
+            if modified < cutoff {
+                Self::remove_backup(&path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// バックアップ本体と `.sha256` サイドカーをまとめて削除する。
+    ///
+    /// サイドカーを残すと保持ポリシーのたびに孤児ファイルが溜まる。
+    fn remove_backup(path: &Path) -> Result<(), PersistenceError> {
+        std::fs::remove_file(path)?;
+        let checksum = Self::checksum_path(path);
+        if checksum.exists() {
+            std::fs::remove_file(checksum)?;
+        }
+        Ok(())
+    }
+
+    /// 件数と日数の両条件を満たすバックアップだけを残す。
+    ///
+    /// 「最新 `keep_count` 件以内」かつ「`max_age` 以内」のものだけが
+    /// 残り、どちらかを超えたものは削除される。削除件数を返す。
+    pub fn cleanup(
+        &self,
+        keep_count: usize,
+        max_age: Duration,
+    ) -> Result<usize, PersistenceError> {
+        let removed_by_count = self.cleanup_old_backups(keep_count)?;
+        let removed_by_age = self.cleanup_by_age(max_age)?;
+        Ok(removed_by_count + removed_by_age)
+    }
+
+    /// バックアップファイル名から元のファイル名を取り出す。
+    ///
+    /// `<name>.<timestamp>.bak` と `<name>.<timestamp>.bak.gz` の両対応。
+    /// persist コマンドの復元先解決にも使われる。
+    pub fn original_name(path: &Path) -> Option<String> {
+        let name = path.file_name()?.to_str()?;
+        // 圧縮版は末尾の .gz を外してから .<timestamp>.bak を取り除く
+        let name = name.strip_suffix(".gz").unwrap_or(name);
+        let parts: Vec<&str> = name.split('.').collect();
+        if parts.len() < 3 {
+            return None;
+        }
+        Some(parts[..parts.len() - 2].join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_and_restore_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, r#"{"key": "value"}"#).unwrap();
+
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup_path = adapter.backup(&source).unwrap();
+        std::fs::write(&source, "corrupted").unwrap();
+
+        adapter.restore(&backup_path, &source).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&source).unwrap(),
+            r#"{"key": "value"}"#
+        );
+    }
+
+    #[test]
+    fn test_compressed_backup_roundtrip_and_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        // 圧縮が効くよう繰り返しの多い内容にする
+        let content = r#"{"key": "value"}"#.repeat(200);
+        std::fs::write(&source, &content).unwrap();
+
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup_path = adapter.backup_compressed(&source).unwrap();
+        assert!(backup_path.to_string_lossy().ends_with(".bak.gz"));
+
+        // 圧縮版の方が元より小さい
+        let original_size = std::fs::metadata(&source).unwrap().len();
+        let compressed_size = std::fs::metadata(&backup_path).unwrap().len();
+        assert!(compressed_size < original_size);
+
+        // 復元で内容が一致する
+        std::fs::write(&source, "corrupted").unwrap();
+        adapter.restore(&backup_path, &source).unwrap();
+        assert_eq!(std::fs::read_to_string(&source).unwrap(), content);
+
+        // 圧縮・非圧縮の両方が列挙される
+        adapter.backup(&source).unwrap();
+        assert_eq!(adapter.list_backups().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_restore_verified_accepts_intact_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "important data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup = adapter.backup(&source).unwrap();
+
+        std::fs::write(&source, "corrupted").unwrap();
+        adapter.restore_verified(&backup, &source).unwrap();
+        assert_eq!(std::fs::read_to_string(&source).unwrap(), "important data");
+    }
+
+    #[test]
+    fn test_restore_verified_rejects_tampered_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "important data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+        let backup = adapter.backup(&source).unwrap();
+
+        // バックアップを1バイト改変する
+        let mut content = std::fs::read(&backup).unwrap();
+        content[0] ^= 0xff;
+        std::fs::write(&backup, content).unwrap();
+
+        std::fs::write(&source, "current").unwrap();
+        let err = adapter.restore_verified(&backup, &source);
+        assert!(matches!(err, Err(PersistenceError::ChecksumMismatch(_))));
+        // ターゲットは書き換えられていない
+        assert_eq!(std::fs::read_to_string(&source).unwrap(), "current");
+    }
+
+    #[test]
+    fn test_restore_missing_backup_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = BackupAdapter::new(dir.path());
+        let err = adapter.restore(&dir.path().join("nope.bak"), &dir.path().join("t"));
+        assert!(matches!(err, Err(PersistenceError::BackupNotFound(_))));
+    }
+
+    /// ファイルの更新時刻を過去へずらすテストヘルパ。
+    fn age_file(path: &Path, age: Duration) {
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    #[test]
+    fn test_cleanup_by_age_removes_old_backups_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+
+        let old = adapter.backup(&source).unwrap();
+        let fresh = adapter.backup_compressed(&source).unwrap();
+        age_file(&old, Duration::from_secs(40 * 24 * 3600));
+
+        let removed = adapter.cleanup_by_age(Duration::from_secs(30 * 24 * 3600)).unwrap();
+        assert_eq!(removed, 1);
+        assert!(!old.exists());
+        assert!(fresh.exists());
+        // 削除されたバックアップのサイドカーも残らない
+        let mut old_sidecar = old.into_os_string();
+        old_sidecar.push(".sha256");
+        assert!(!std::path::Path::new(&old_sidecar).exists());
+    }
+
+    #[test]
+    fn test_cleanup_by_age_keeps_file_exactly_at_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+
+        let backup = adapter.backup(&source).unwrap();
+        // 更新時刻をちょうど閾値に合わせる（cutoff と同時刻 → 削除されない）
+        let max_age = Duration::from_secs(3600);
+        let cutoff = SystemTime::now() - max_age + Duration::from_secs(5);
+        let file = std::fs::File::options().write(true).open(&backup).unwrap();
+        file.set_modified(cutoff).unwrap();
+
+        let removed = adapter.cleanup_by_age(max_age).unwrap();
+        assert_eq!(removed, 0);
+        assert!(backup.exists());
+    }
+
+    #[test]
+    fn test_combined_cleanup_applies_both_conditions() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+
+        let mut backups = Vec::new();
+        for _ in 0..4 {
+            backups.push(adapter.backup(&source).unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        // 最新のファイルを日数超過にする
+        age_file(backups.last().unwrap(), Duration::from_secs(3600 * 24 * 60));
+
+        // 件数 3 で最古1件、日数 30 日で最新1件（古い mtime に偽装済み）が消える
+        let removed = adapter
+            .cleanup(3, Duration::from_secs(3600 * 24 * 30))
+            .unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(adapter.list_backups().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_cleanup_keeps_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("state.json");
+        std::fs::write(&source, "data").unwrap();
+        let adapter = BackupAdapter::new(dir.path().join("backups"));
+
+        for _ in 0..5 {
+            adapter.backup(&source).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        let removed = adapter.cleanup_old_backups(DEFAULT_KEEP_COUNT).unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(adapter.list_backups().unwrap().len(), 3);
+        // サイドカーも本体と一緒に消え、孤児が残らない
+        let sidecars = std::fs::read_dir(adapter.backup_dir())
+            .unwrap()
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".sha256"))
+            .count();
+        assert_eq!(sidecars, 3);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod backup_adapter;
+
+pub use backup_adapter::BackupAdapter;
@@ -0,0 +1,4 @@
+//! アダプター — 外部サービス・ファイルシステムへのポート実装。
+
+pub mod file;
+pub mod quality;
@@ -0,0 +1,97 @@
+use aad_domain::services::{TestOutcome, TestRunner};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// `cargo test` を実行して結果をパースするデフォルトの TestRunner。
+#[derive(Debug, Clone)]
+pub struct CargoTestRunner {
+    /// テストを実行するディレクトリ。
+    working_dir: PathBuf,
+}
+
+impl CargoTestRunner {
+    pub fn new(working_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            working_dir: working_dir.into(),
+        }
+    }
+
+    /// `test result: ok. 3 passed; 0 failed; ...` 形式の行を集計する。
+    pub(crate) fn parse_output(output: &str) -> TestOutcome {
+        let mut outcome = TestOutcome {
+            passed: 0,
+            failed: 0,
+        };
+        for line in output.lines() {
+            let Some(rest) = line.trim().strip_prefix("test result:") else {
+                continue;
+            };
+            // 例: " ok. 3 passed" / " 1 failed" — 末尾の語の直前が件数
+            for part in rest.split(';') {
+                let tokens: Vec<&str> = part.split_whitespace().collect();
+                let (Some(kind), Some(count)) = (tokens.last(), tokens.len().checked_sub(2).and_then(|i| tokens.get(i))) else {
+                    continue;
+                };
+                let Ok(n) = count.parse::<usize>() else {
+                    continue;
+                };
+                match *kind {
+                    "passed" => outcome.passed += n,
+                    "failed" => outcome.failed += n,
+                    _ => {}
+                }
+            }
+        }
+        outcome
+    }
+}
+
+impl TestRunner for CargoTestRunner {
+    fn run_tests(&self) -> Result<TestOutcome, String> {
+        let output = Command::new("cargo")
+            .arg("test")
+            .current_dir(&self.working_dir)
+            .output()
+            .map_err(|e| format!("failed to spawn cargo test: {e}"))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let outcome = Self::parse_output(&stdout);
+        // テスト失敗は Outcome の failed で表現する。ビルドエラーなど
+        // テスト結果自体が得られない失敗だけを Err にする
+        if !output.status.success() && outcome.passed == 0 && outcome.failed == 0 {
+            return Err(String::from_utf8_lossy(&output.stderr)
+                .lines()
+                .last()
+                .unwrap_or("cargo test failed")
+                .to_string());
+        }
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_sums_result_lines() {
+        let output = "\
+running 3 tests
+test a ... ok
+test result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out
+
+running 2 tests
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+";
+        let outcome = CargoTestRunner::parse_output(output);
+        assert_eq!(outcome.passed, 4);
+        assert_eq!(outcome.failed, 1);
+    }
+
+    #[test]
+    fn test_parse_output_empty_for_no_results() {
+        let outcome = CargoTestRunner::parse_output("compilation error\n");
+        assert_eq!(outcome.passed, 0);
+        assert_eq!(outcome.failed, 0);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cargo_test_runner;
+pub mod llvm_cov_provider;
+
+pub use cargo_test_runner::CargoTestRunner;
+pub use llvm_cov_provider::LlvmCovProvider;
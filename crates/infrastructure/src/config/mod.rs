@@ -0,0 +1,8 @@
+//! 設定管理 — `.aad/config.toml` の読み込み。
+
+pub mod toml_loader;
+
+pub use toml_loader::{
+    AadConfig, ConfigError, GeneralConfig, LoopConfig, MonitorConfig, NotificationConfig,
+    OrchestrationConfig, PersistenceConfig, WorkflowConfig,
+};
@@ -0,0 +1,386 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 設定読み込みのエラー。
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("parse error: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("invalid value: {0}")]
+    Invalid(String),
+}
+
+/// `.aad/config.toml` のスキーマ。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AadConfig {
+    #[serde(default)]
+    pub general: GeneralConfig,
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    #[serde(default)]
+    pub orchestration: OrchestrationConfig,
+    #[serde(default)]
+    pub monitor: MonitorConfig,
+    #[serde(default, rename = "loop")]
+    pub loop_engine: LoopConfig,
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    #[serde(default)]
+    pub persistence: PersistenceConfig,
+}
+
+/// `[persistence]` セクション。バックアップと state の保存先を設定する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistenceConfig {
+    /// バックアップの保存先ディレクトリ。
+    pub backup_dir: PathBuf,
+    /// オーケストレーター state の保存先ディレクトリ。
+    pub state_dir: PathBuf,
+    /// 件数ベースの保持数。
+    pub keep_count: usize,
+}
+
+impl Default for PersistenceConfig {
+    fn default() -> Self {
+        Self {
+            backup_dir: PathBuf::from(".aad/backups"),
+            state_dir: PathBuf::from(".aad/orchestration"),
+            keep_count: 3,
+        }
+    }
+}
+
+/// `[notifications]` セクション。Webhook 通知などの設定を一元管理する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    /// 通知を有効にするか。
+    pub enabled: bool,
+    /// 通知先 Webhook URL。
+    pub webhook_url: Option<String>,
+    /// 通知する最小レベル（info/warning/critical）。
+    pub min_level: String,
+    /// エスカレーションのバッチ集約ウィンドウ（秒）。未設定なら即時通知。
+    #[serde(default)]
+    pub batch_window_secs: Option<u64>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            webhook_url: None,
+            min_level: "warning".to_string(),
+            batch_window_secs: None,
+        }
+    }
+}
+
+impl NotificationConfig {
+    /// EscalationHandler が使う `NotificationSettings` へ変換する。
+    ///
+    /// `min_level` の文字列（info/warning/critical）をパースし、
+    /// 不正な値は設定エラーとして報告する。
+    pub fn to_settings(
+        &self,
+    ) -> Result<aad_application::services::NotificationSettings, ConfigError> {
+        let min_level = self.min_level.parse().map_err(ConfigError::Invalid)?;
+        Ok(aad_application::services::NotificationSettings {
+            enabled: self.enabled,
+            webhook_url: self.webhook_url.clone(),
+            min_level,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneralConfig {
+    /// プロジェクト名。
+    pub project_name: String,
+    /// `.aad` データディレクトリ。
+    pub data_dir: PathBuf,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            project_name: String::new(),
+            data_dir: PathBuf::from(".aad"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    /// SPEC/TASKS/REVIEW フェーズで人間承認を必須にするか。
+    pub require_approval: bool,
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        Self {
+            require_approval: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationConfig {
+    /// 同時実行 Spec 数。
+    pub max_parallel_specs: u8,
+    /// Spec 内の同時実行タスク数。
+    pub max_parallel_tasks: u8,
+    /// 監視ループのポーリング間隔（ミリ秒）。
+    pub polling_interval_ms: u64,
+    /// タスクの最大実行時間（分）。
+    pub max_task_duration_min: u64,
+    /// セッション失敗時の最大リトライ回数。
+    pub max_retries: u32,
+    /// リトライ前の待機秒数。
+    pub retry_delay_secs: u64,
+}
+
+impl Default for OrchestrationConfig {
+    fn default() -> Self {
+        Self {
+            max_parallel_specs: 2,
+            max_parallel_tasks: 2,
+            polling_interval_ms: 3000,
+            max_task_duration_min: 60,
+            max_retries: 3,
+            retry_delay_secs: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorConfig {
+    /// TUI の再描画間隔（ミリ秒）。
+    pub refresh_rate_ms: u64,
+}
+
+impl Default for MonitorConfig {
+    fn default() -> Self {
+        Self {
+            refresh_rate_ms: 250,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopConfig {
+    /// タスクごとの最大リトライ回数。
+    pub max_retries: u32,
+    /// ループ状態ファイルのパス。
+    pub state_file: PathBuf,
+}
+
+impl Default for LoopConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            state_file: PathBuf::from(".aad/loop-state.json"),
+        }
+    }
+}
+
+impl AadConfig {
+    /// 全セクションのコメント付きデフォルトを含む config.toml の雛形を生成する。
+    ///
+    /// `aad init` が生成し、ユーザーがどの設定項目があるか一覧できる。
+    /// 生成された TOML はそのまま `AadConfig::load` で読める。
+    pub fn generate_template() -> String {
+        r#"# aad 設定ファイル
+# 各項目はデフォルト値。不要なセクションは削除してもよい（デフォルトが使われる）。
+
+[general]
+# project_name: プロジェクト名
+project_name = ""
+# data_dir: .aad データディレクトリ
+data_dir = ".aad"
+
+[workflow]
+# require_approval: SPEC/TASKS/REVIEW フェーズで人間承認を必須にするか
+require_approval = true
+
+[orchestration]
+# max_parallel_specs: 同時実行 Spec 数
+max_parallel_specs = 2
+# max_parallel_tasks: Spec 内の同時実行タスク数
+max_parallel_tasks = 2
+# polling_interval_ms: 監視ループのポーリング間隔（ミリ秒）
+polling_interval_ms = 3000
+# max_task_duration_min: タスクの最大実行時間（分）
+max_task_duration_min = 60
+# max_retries: セッション失敗時の最大リトライ回数
+max_retries = 3
+# retry_delay_secs: リトライ前の待機秒数（指数バックオフの初期値）
+retry_delay_secs = 5
+
+[monitor]
+# refresh_rate_ms: TUI の再描画間隔（ミリ秒）
+refresh_rate_ms = 250
+
+[loop]
+# max_retries: タスクごとの最大リトライ回数
+max_retries = 3
+# state_file: ループ状態ファイルのパス
+state_file = ".aad/loop-state.json"
+
+[persistence]
+# backup_dir: バックアップの保存先ディレクトリ
+backup_dir = ".aad/backups"
+# state_dir: オーケストレーター state の保存先ディレクトリ
+state_dir = ".aad/orchestration"
+# keep_count: バックアップの保持数
+keep_count = 3
+
+[notifications]
+# enabled: 通知を有効にするか
+enabled = false
+# webhook_url: 通知先 Webhook URL（未設定なら通知しない）
+# webhook_url = "https://example.com/hook"
+# min_level: 通知する最小レベル（info/warning/critical）
+min_level = "warning"
+# batch_window_secs: エスカレーションのバッチ集約ウィンドウ（秒）。
+# 未設定なら即時通知（Critical は常に即時）
+# batch_window_secs = 300
+"#
+        .to_string()
+    }
+
+    /// 通知設定を返す。EscalationHandler が通知先の決定に使う。
+    pub fn notification_config(&self) -> &NotificationConfig {
+        &self.notifications
+    }
+
+    /// 設定ファイルを読み込む。
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// 設定ファイルが存在しなければデフォルトを返す。
+    pub fn load_or_default(path: &Path) -> Result<Self, ConfigError> {
+        if path.exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_partial_config_fills_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[orchestration]\nmax_parallel_specs = 4\nmax_parallel_tasks = 2\npolling_interval_ms = 1000\nmax_task_duration_min = 30\nmax_retries = 5\nretry_delay_secs = 1\n",
+        )
+        .unwrap();
+
+        let config = AadConfig::load(&path).unwrap();
+        assert_eq!(config.orchestration.max_parallel_specs, 4);
+        // 省略セクションはデフォルト
+        assert!(config.workflow.require_approval);
+        assert_eq!(config.monitor.refresh_rate_ms, 250);
+    }
+
+    #[test]
+    fn test_generated_template_is_loadable() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, AadConfig::generate_template()).unwrap();
+
+        let config = AadConfig::load(&path).unwrap();
+        // 雛形の値はデフォルトと一致する
+        assert_eq!(config.orchestration.max_parallel_specs, 2);
+        assert_eq!(config.monitor.refresh_rate_ms, 250);
+        assert!(!config.notification_config().enabled);
+        assert_eq!(config.loop_engine.max_retries, 3);
+    }
+
+    #[test]
+    fn test_persistence_section_loads_with_fallback() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[persistence]\nbackup_dir = \"/mnt/backups\"\nstate_dir = \"/mnt/state\"\nkeep_count = 10\n",
+        )
+        .unwrap();
+
+        let config = AadConfig::load(&path).unwrap();
+        assert_eq!(config.persistence.backup_dir, PathBuf::from("/mnt/backups"));
+        assert_eq!(config.persistence.keep_count, 10);
+
+        // セクション省略時は従来のデフォルト
+        let default = AadConfig::default();
+        assert_eq!(
+            default.persistence.backup_dir,
+            PathBuf::from(".aad/backups")
+        );
+        assert_eq!(default.persistence.keep_count, 3);
+    }
+
+    #[test]
+    fn test_notification_config_converts_to_settings() {
+        use aad_application::services::EscalationLevel;
+
+        let config = NotificationConfig {
+            enabled: true,
+            webhook_url: Some("https://example.com/hook".to_string()),
+            min_level: "critical".to_string(),
+            batch_window_secs: None,
+        };
+        let settings = config.to_settings().unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.min_level, EscalationLevel::Critical);
+
+        // 不正なレベルは設定エラー
+        let broken = NotificationConfig {
+            min_level: "loud".to_string(),
+            ..NotificationConfig::default()
+        };
+        assert!(matches!(broken.to_settings(), Err(ConfigError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_notifications_section_loads_and_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[notifications]\nenabled = true\nwebhook_url = \"https://example.com/hook\"\nmin_level = \"critical\"\n",
+        )
+        .unwrap();
+
+        let config = AadConfig::load(&path).unwrap();
+        let notifications = config.notification_config();
+        assert!(notifications.enabled);
+        assert_eq!(
+            notifications.webhook_url.as_deref(),
+            Some("https://example.com/hook")
+        );
+        assert_eq!(notifications.min_level, "critical");
+
+        // セクション省略時は無効
+        let default = AadConfig::default();
+        assert!(!default.notification_config().enabled);
+    }
+
+    #[test]
+    fn test_load_or_default_when_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = AadConfig::load_or_default(&dir.path().join("nope.toml")).unwrap();
+        assert_eq!(config.orchestration.max_parallel_specs, 2);
+    }
+}
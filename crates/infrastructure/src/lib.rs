@@ -0,0 +1,8 @@
+//! インフラストラクチャ層 — 外部サービス・ファイルシステムとの接続。
+//!
+//! domain 層のリポジトリトレイトの JSON ファイル実装、バックアップ、
+//! TOML 設定ローダーを提供する。
+
+pub mod adapters;
+pub mod config;
+pub mod persistence;
@@ -0,0 +1,111 @@
+use crate::persistence::PersistenceError;
+use aad_domain::value_objects::{Phase, SpecId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 品質ゲートの承認記録。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateApproval {
+    pub spec_id: SpecId,
+    pub phase: Phase,
+    /// 承認者。
+    pub approver: String,
+    pub approved_at: DateTime<Utc>,
+}
+
+/// ゲート承認を `.aad/data/gates/<spec_id>-<phase>.json` として永続化する
+/// リポジトリ。
+///
+/// `aad gate` の再実行時に過去の承認を引き継げる。承認はフェーズ単位
+/// なので、別フェーズには適用されない。
+#[derive(Debug, Clone)]
+pub struct GateJsonRepo {
+    base_dir: PathBuf,
+}
+
+impl GateJsonRepo {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn path_for(&self, spec_id: &SpecId, phase: &Phase) -> PathBuf {
+        self.base_dir.join(format!("{spec_id}-{phase}.json"))
+    }
+
+    /// 承認記録を保存する。
+    pub fn save_approval(&self, approval: &GateApproval) -> Result<(), PersistenceError> {
+        std::fs::create_dir_all(&self.base_dir)?;
+        let json = serde_json::to_string_pretty(approval)?;
+        aad_application::services::write_atomic(
+            &self.path_for(&approval.spec_id, &approval.phase),
+            &json,
+        )?;
+        Ok(())
+    }
+
+    /// 指定 Spec・フェーズの承認記録を読み込む。無ければ `None`。
+    pub fn find_approval(
+        &self,
+        spec_id: &SpecId,
+        phase: &Phase,
+    ) -> Result<Option<GateApproval>, PersistenceError> {
+        let path = self.path_for(spec_id, phase);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approval(phase: Phase) -> GateApproval {
+        GateApproval {
+            spec_id: SpecId::from("SPEC-001"),
+            phase,
+            approver: "reviewer".to_string(),
+            approved_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_approval_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = GateJsonRepo::new(dir.path());
+        repo.save_approval(&approval(Phase::Review)).unwrap();
+
+        let found = repo
+            .find_approval(&SpecId::from("SPEC-001"), &Phase::Review)
+            .unwrap()
+            .expect("approval should persist");
+        assert_eq!(found.approver, "reviewer");
+    }
+
+    #[test]
+    fn test_approval_is_phase_scoped() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = GateJsonRepo::new(dir.path());
+        repo.save_approval(&approval(Phase::Review)).unwrap();
+
+        // 別フェーズには適用されない
+        assert!(repo
+            .find_approval(&SpecId::from("SPEC-001"), &Phase::Merge)
+            .unwrap()
+            .is_none());
+        // 別 Spec にも適用されない
+        assert!(repo
+            .find_approval(&SpecId::from("SPEC-002"), &Phase::Review)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,29 @@
+//! 永続化実装 — `.aad/data/` 配下への JSON ファイル保存。
+
+pub mod gate_json_repo;
+pub mod session_json_repo;
+pub mod spec_dependencies;
+pub mod spec_json_repo;
+pub mod task_json_repo;
+
+pub use gate_json_repo::{GateApproval, GateJsonRepo};
+pub use session_json_repo::SessionJsonRepo;
+pub use spec_dependencies::{load_spec_dependencies, SpecDependencies};
+pub use spec_json_repo::SpecJsonRepo;
+pub use task_json_repo::TaskJsonRepo;
+
+/// 永続化操作のエラー。
+#[derive(Debug, thiserror::Error)]
+pub enum PersistenceError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("backup not found: {0}")]
+    BackupNotFound(String),
+
+    #[error("checksum mismatch for {0} (backup may be corrupted)")]
+    ChecksumMismatch(String),
+}
@@ -0,0 +1,104 @@
+use aad_domain::entities::Session;
+use aad_domain::repositories::{RepositoryError, SessionRepository};
+use aad_domain::value_objects::SessionId;
+use std::path::{Path, PathBuf};
+
+/// Session を `.aad/data/sessions/<id>.json` として保存するリポジトリ。
+#[derive(Debug, Clone)]
+pub struct SessionJsonRepo {
+    base_dir: PathBuf,
+}
+
+impl SessionJsonRepo {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn path_for(&self, id: &SessionId) -> PathBuf {
+        self.base_dir.join(format!("{id}.json"))
+    }
+}
+
+impl SessionRepository for SessionJsonRepo {
+    fn save(&self, session: &Session) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string_pretty(session)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        // 書き込み途中の中断で既存ファイルが破損しないようアトミックに置き換える
+        aad_application::services::write_atomic(&self.path_for(&session.id), &json)?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, RepositoryError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let session = serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        Ok(Some(session))
+    }
+
+    fn find_all(&self) -> Result<Vec<Session>, RepositoryError> {
+        let mut sessions = Vec::new();
+        if !self.base_dir.exists() {
+            return Ok(sessions);
+        }
+        for entry in std::fs::read_dir(&self.base_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                let content = std::fs::read_to_string(&path)?;
+                let session = serde_json::from_str(&content)
+                    .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+                sessions.push(session);
+            }
+        }
+        sessions.sort_by(|a: &Session, b: &Session| a.id.cmp(&b.id));
+        Ok(sessions)
+    }
+
+    fn find_active(&self) -> Result<Vec<Session>, RepositoryError> {
+        Ok(self
+            .find_all()?
+            .into_iter()
+            .filter(|s| !s.is_terminal())
+            .collect())
+    }
+
+    fn delete(&self, id: &SessionId) -> Result<(), RepositoryError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Err(RepositoryError::NotFound(id.to_string()));
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aad_domain::value_objects::{Phase, SessionStatus, SpecId};
+
+    #[test]
+    fn test_find_active_excludes_terminal() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SessionJsonRepo::new(dir.path());
+
+        let running = Session::new(SpecId::from("SPEC-001"), Phase::Tdd);
+        let mut done = Session::new(SpecId::from("SPEC-002"), Phase::Tdd);
+        done.change_status(SessionStatus::Completed);
+        repo.save(&running).unwrap();
+        repo.save(&done).unwrap();
+
+        let active = repo.find_active().unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, running.id);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::persistence::PersistenceError;
+use aad_domain::value_objects::SpecId;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// `.aad/specs/<SPEC-ID>/dependencies.json` のフォーマット。
+///
+/// ```json
+/// {"depends_on": ["SPEC-001"]}
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpecDependencies {
+    #[serde(default)]
+    pub depends_on: Vec<SpecId>,
+}
+
+/// Spec の依存関係ファイルを読み込む。
+///
+/// ファイルが無い spec は依存なしとして空を返す。
+pub fn load_spec_dependencies(
+    specs_dir: &Path,
+    spec_id: &SpecId,
+) -> Result<SpecDependencies, PersistenceError> {
+    let path = specs_dir.join(spec_id.as_str()).join("dependencies.json");
+    if !path.exists() {
+        return Ok(SpecDependencies::default());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_reads_depends_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_dir = dir.path().join("SPEC-002");
+        std::fs::create_dir_all(&spec_dir).unwrap();
+        std::fs::write(
+            spec_dir.join("dependencies.json"),
+            r#"{"depends_on": ["SPEC-001"]}"#,
+        )
+        .unwrap();
+
+        let deps =
+            load_spec_dependencies(dir.path(), &SpecId::from("SPEC-002")).unwrap();
+        assert_eq!(deps.depends_on, vec![SpecId::from("SPEC-001")]);
+    }
+
+    #[test]
+    fn test_missing_file_means_no_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let deps =
+            load_spec_dependencies(dir.path(), &SpecId::from("SPEC-001")).unwrap();
+        assert!(deps.depends_on.is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use aad_domain::entities::Spec;
+use aad_domain::repositories::{RepositoryError, SpecRepository};
+use aad_domain::value_objects::{SpecId, SpecLifecycle};
+use std::path::{Path, PathBuf};
+
+/// Spec を `.aad/data/specs/<id>.json` として保存するリポジトリ。
+#[derive(Debug, Clone)]
+pub struct SpecJsonRepo {
+    base_dir: PathBuf,
+}
+
+impl SpecJsonRepo {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn path_for(&self, id: &SpecId) -> PathBuf {
+        self.base_dir.join(format!("{id}.json"))
+    }
+
+    /// `SPEC-NNN` 形式の連番で次の Spec ID を採番する。
+    ///
+    /// 既存の `SPEC-NNN` の最大番号 +1 を返す。連番形式の Spec が
+    /// 無ければ `SPEC-001`。
+    pub fn next_spec_id(&self) -> Result<SpecId, RepositoryError> {
+        let max = self
+            .find_all()?
+            .iter()
+            .filter_map(|s| parse_seq_number(s.id.as_str(), "SPEC-"))
+            .max()
+            .unwrap_or(0);
+        Ok(SpecId::from(format!("SPEC-{:03}", max + 1)))
+    }
+
+    /// 必須フィールドの存在と妥当性を検証する。
+    ///
+    /// 外部ツールや手編集で作られた JSON は `serde_json` のパースを
+    /// 通っても空フィールドを持ち得るため、読み込み後に明示的に
+    /// 検証する。失敗時は該当フィールド名を含むエラーを返す。
+    pub fn validate_spec(spec: &Spec) -> Result<(), RepositoryError> {
+        if spec.id.as_str().trim().is_empty() {
+            return Err(RepositoryError::Validation(
+                "spec field `id` must not be empty".to_string(),
+            ));
+        }
+        if spec.name.trim().is_empty() {
+            return Err(RepositoryError::Validation(format!(
+                "spec {}: field `name` must not be empty",
+                spec.id
+            )));
+        }
+        // description は `aad spec` が空で作ることを許しているため、
+        // ここでは ID と name のみを必須とする
+        Ok(())
+    }
+
+    /// 指定ライフサイクル状態の Spec だけを返す。
+    pub fn find_by_lifecycle(
+        &self,
+        lifecycle: SpecLifecycle,
+    ) -> Result<Vec<Spec>, RepositoryError> {
+        Ok(self
+            .find_all()?
+            .into_iter()
+            .filter(|s| s.lifecycle == lifecycle)
+            .collect())
+    }
+}
+
+/// `<prefix>NNN` 形式の ID から連番部分を取り出す。
+pub(crate) fn parse_seq_number(id: &str, prefix: &str) -> Option<u32> {
+    id.strip_prefix(prefix)?.parse().ok()
+}
+
+impl SpecRepository for SpecJsonRepo {
+    fn save(&self, spec: &Spec) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string_pretty(spec)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        // 書き込み途中の中断で既存ファイルが破損しないようアトミックに置き換える
+        aad_application::services::write_atomic(&self.path_for(&spec.id), &json)?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &SpecId) -> Result<Option<Spec>, RepositoryError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let spec: Spec = serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        Self::validate_spec(&spec)?;
+        Ok(Some(spec))
+    }
+
+    fn find_all(&self) -> Result<Vec<Spec>, RepositoryError> {
+        let mut specs = Vec::new();
+        if !self.base_dir.exists() {
+            return Ok(specs);
+        }
+        for entry in std::fs::read_dir(&self.base_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                let content = std::fs::read_to_string(&path)?;
+                let spec = serde_json::from_str(&content)
+                    .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+                specs.push(spec);
+            }
+        }
+        specs.sort_by(|a: &Spec, b: &Spec| a.id.cmp(&b.id));
+        Ok(specs)
+    }
+
+    fn delete(&self, id: &SpecId) -> Result<(), RepositoryError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Err(RepositoryError::NotFound(id.to_string()));
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_find_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        let spec = Spec::new(SpecId::from("SPEC-001"), "auth", "JWT 認証機能");
+        repo.save(&spec).unwrap();
+
+        let found = repo.find_by_id(&spec.id).unwrap().unwrap();
+        assert_eq!(found.name, "auth");
+        assert!(repo.find_by_id(&SpecId::from("SPEC-999")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_all_sorted_by_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        repo.save(&Spec::new(SpecId::from("SPEC-002"), "b", "d")).unwrap();
+        repo.save(&Spec::new(SpecId::from("SPEC-001"), "a", "d")).unwrap();
+
+        let all = repo.find_all().unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].id, SpecId::from("SPEC-001"));
+    }
+
+    #[test]
+    fn test_find_by_lifecycle_filters() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+
+        let active = Spec::new(SpecId::from("SPEC-001"), "a", "d");
+        let mut draft = Spec::new(SpecId::from("SPEC-002"), "b", "d");
+        draft.change_lifecycle(SpecLifecycle::Draft);
+        repo.save(&active).unwrap();
+        repo.save(&draft).unwrap();
+
+        let actives = repo.find_by_lifecycle(SpecLifecycle::Active).unwrap();
+        assert_eq!(actives.len(), 1);
+        assert_eq!(actives[0].id, SpecId::from("SPEC-001"));
+        assert_eq!(repo.find_by_lifecycle(SpecLifecycle::Archived).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_next_spec_id_increments_max_number() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        assert_eq!(repo.next_spec_id().unwrap(), SpecId::from("SPEC-001"));
+
+        repo.save(&Spec::new(SpecId::from("SPEC-002"), "a", "d")).unwrap();
+        repo.save(&Spec::new(SpecId::from("SPEC-010"), "b", "d")).unwrap();
+        // 連番でない ID は無視される
+        repo.save(&Spec::new(SpecId::from("SPEC-abc123"), "c", "d")).unwrap();
+
+        assert_eq!(repo.next_spec_id().unwrap(), SpecId::from("SPEC-011"));
+    }
+
+    #[test]
+    fn test_find_by_id_rejects_empty_required_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        // name が空の手編集 JSON
+        std::fs::write(
+            dir.path().join("SPEC-001.json"),
+            r#"{"id":"SPEC-001","name":"","description":"d","acceptance_criteria":[],"created_at":"2026-09-01T00:00:00Z","updated_at":"2026-09-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+
+        let err = repo.find_by_id(&SpecId::from("SPEC-001"));
+        match err {
+            Err(RepositoryError::Validation(msg)) => assert!(msg.contains("name")),
+            other => panic!("expected validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_defaults_to_active_for_old_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        // lifecycle フィールドの無い既存フォーマットの JSON
+        std::fs::write(
+            dir.path().join("SPEC-001.json"),
+            r#"{"id":"SPEC-001","name":"a","description":"d","acceptance_criteria":[],"created_at":"2026-09-01T00:00:00Z","updated_at":"2026-09-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+
+        let spec = repo.find_by_id(&SpecId::from("SPEC-001")).unwrap().unwrap();
+        assert!(spec.is_active());
+    }
+
+    #[test]
+    fn test_delete_missing_is_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = SpecJsonRepo::new(dir.path());
+        let err = repo.delete(&SpecId::from("SPEC-404"));
+        assert!(matches!(err, Err(RepositoryError::NotFound(_))));
+    }
+}
@@ -0,0 +1,261 @@
+use aad_domain::entities::Task;
+use aad_domain::repositories::{RepositoryError, TaskRepository};
+use aad_domain::value_objects::{SpecId, TaskId};
+use std::path::{Path, PathBuf};
+
+/// Task を `.aad/data/tasks/<spec_id>/<task_id>.json` として保存するリポジトリ。
+#[derive(Debug, Clone)]
+pub struct TaskJsonRepo {
+    base_dir: PathBuf,
+}
+
+impl TaskJsonRepo {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn path_for(&self, spec_id: &SpecId, task_id: &TaskId) -> PathBuf {
+        self.base_dir
+            .join(spec_id.as_str())
+            .join(format!("{task_id}.json"))
+    }
+
+    /// `<spec_id>-TNN` 形式の連番で Spec 内の次のタスク ID を採番する。
+    pub fn next_task_id(&self, spec_id: &SpecId) -> Result<TaskId, RepositoryError> {
+        let prefix = format!("{spec_id}-T");
+        let max = self
+            .find_by_spec_id(spec_id)?
+            .iter()
+            .filter_map(|t| crate::persistence::spec_json_repo::parse_seq_number(t.id.as_str(), &prefix))
+            .max()
+            .unwrap_or(0);
+        Ok(TaskId::from(format!("{spec_id}-T{:02}", max + 1)))
+    }
+
+    fn read_task(path: &Path) -> Result<Task, RepositoryError> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))
+    }
+}
+
+impl TaskRepository for TaskJsonRepo {
+    fn save(&self, task: &Task) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string_pretty(task)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        // 書き込み途中の中断で既存ファイルが破損しないようアトミックに置き換える
+        aad_application::services::write_atomic(
+            &self.path_for(&task.spec_id, &task.id),
+            &json,
+        )?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, RepositoryError> {
+        if !self.base_dir.exists() {
+            return Ok(None);
+        }
+        // Spec ごとのサブディレクトリを横断して探す
+        for entry in std::fs::read_dir(&self.base_dir)? {
+            let dir = entry?.path();
+            if !dir.is_dir() {
+                continue;
+            }
+            let path = dir.join(format!("{id}.json"));
+            if path.exists() {
+                return Ok(Some(Self::read_task(&path)?));
+            }
+        }
+        Ok(None)
+    }
+
+    fn find_by_spec_id(&self, spec_id: &SpecId) -> Result<Vec<Task>, RepositoryError> {
+        let dir = self.base_dir.join(spec_id.as_str());
+        let mut tasks = Vec::new();
+        if !dir.exists() {
+            return Ok(tasks);
+        }
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                tasks.push(Self::read_task(&path)?);
+            }
+        }
+        tasks.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(tasks)
+    }
+
+    fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        let mut tasks = Vec::new();
+        if !self.base_dir.exists() {
+            return Ok(tasks);
+        }
+        let spec_dirs: Vec<PathBuf> = std::fs::read_dir(&self.base_dir)?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+
+        // Spec ディレクトリ単位で並列に読み込む（大量タスク対策）。
+        // スレッド数は CPU 数程度に抑え、各スレッドが複数ディレクトリを担当する
+        let workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4)
+            .min(spec_dirs.len().max(1));
+        let chunk_size = spec_dirs.len().div_ceil(workers);
+        let results = std::thread::scope(|scope| {
+            let handles: Vec<_> = spec_dirs
+                .chunks(chunk_size.max(1))
+                .map(|dirs| {
+                    scope.spawn(move || -> Result<Vec<Task>, RepositoryError> {
+                        let mut tasks = Vec::new();
+                        for dir in dirs {
+                            for entry in std::fs::read_dir(dir)? {
+                                let path = entry?.path();
+                                if path.extension().is_some_and(|ext| ext == "json") {
+                                    tasks.push(Self::read_task(&path)?);
+                                }
+                            }
+                        }
+                        Ok(tasks)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("task reader thread panicked"))
+                .collect::<Vec<_>>()
+        });
+        for result in results {
+            tasks.extend(result?);
+        }
+        tasks.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(tasks)
+    }
+
+    fn delete(&self, id: &TaskId) -> Result<(), RepositoryError> {
+        if self.base_dir.exists() {
+            for entry in std::fs::read_dir(&self.base_dir)? {
+                let dir = entry?.path();
+                if !dir.is_dir() {
+                    continue;
+                }
+                let path = dir.join(format!("{id}.json"));
+                if path.exists() {
+                    std::fs::remove_file(path)?;
+                    return Ok(());
+                }
+            }
+        }
+        Err(RepositoryError::NotFound(id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aad_domain::value_objects::{Complexity, Priority};
+
+    fn make_task(spec: &str, id: &str) -> Task {
+        Task::new(
+            TaskId::from(id),
+            SpecId::from(spec),
+            id,
+            Priority::Must,
+            Complexity::Small,
+        )
+    }
+
+    #[test]
+    fn test_save_and_find_by_spec_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        repo.save(&make_task("SPEC-001", "SPEC-001-T01")).unwrap();
+        repo.save(&make_task("SPEC-001", "SPEC-001-T02")).unwrap();
+        repo.save(&make_task("SPEC-002", "SPEC-002-T01")).unwrap();
+
+        let tasks = repo.find_by_spec_id(&SpecId::from("SPEC-001")).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].id, TaskId::from("SPEC-001-T01"));
+    }
+
+    #[test]
+    fn test_next_task_id_increments_within_spec() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        let spec_id = SpecId::from("SPEC-001");
+        assert_eq!(
+            repo.next_task_id(&spec_id).unwrap(),
+            TaskId::from("SPEC-001-T01")
+        );
+
+        repo.save(&make_task("SPEC-001", "SPEC-001-T03")).unwrap();
+        repo.save(&make_task("SPEC-002", "SPEC-002-T09")).unwrap();
+
+        // Spec 内の最大番号 +1（他 Spec の番号には影響されない）
+        assert_eq!(
+            repo.next_task_id(&spec_id).unwrap(),
+            TaskId::from("SPEC-001-T04")
+        );
+    }
+
+    #[test]
+    fn test_count_by_status_matches_actual_counts() {
+        use aad_domain::value_objects::Status;
+
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        let spec_id = SpecId::from("SPEC-001");
+
+        let mut done = make_task("SPEC-001", "SPEC-001-T01");
+        done.change_status(Status::Completed);
+        repo.save(&done).unwrap();
+        repo.save(&make_task("SPEC-001", "SPEC-001-T02")).unwrap();
+        repo.save(&make_task("SPEC-001", "SPEC-001-T03")).unwrap();
+
+        let counts = repo.count_by_status(&spec_id).unwrap();
+        assert_eq!(counts.get(&Status::Completed), Some(&1));
+        assert_eq!(counts.get(&Status::Pending), Some(&2));
+        assert_eq!(counts.get(&Status::Failed), None);
+        assert_eq!(counts.values().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_find_all_returns_tasks_across_specs() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        repo.save(&make_task("SPEC-001", "SPEC-001-T01")).unwrap();
+        repo.save(&make_task("SPEC-001", "SPEC-001-T02")).unwrap();
+        repo.save(&make_task("SPEC-002", "SPEC-002-T01")).unwrap();
+
+        let all = repo.find_all().unwrap();
+        assert_eq!(all.len(), 3);
+        // ID 順で返る
+        assert_eq!(all[0].id, TaskId::from("SPEC-001-T01"));
+        assert_eq!(all[2].id, TaskId::from("SPEC-002-T01"));
+    }
+
+    #[test]
+    fn test_find_all_empty_when_no_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        assert!(repo.find_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_find_by_id_searches_across_specs() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = TaskJsonRepo::new(dir.path());
+        repo.save(&make_task("SPEC-002", "SPEC-002-T01")).unwrap();
+
+        let found = repo.find_by_id(&TaskId::from("SPEC-002-T01")).unwrap();
+        assert!(found.is_some());
+        assert!(repo.find_by_id(&TaskId::from("SPEC-404-T99")).unwrap().is_none());
+    }
+}
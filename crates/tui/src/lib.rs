@@ -0,0 +1,51 @@
+//! TUI 層 — Ratatui ベースの監視ダッシュボード。
+
+pub mod app;
+pub mod state;
+pub mod theme;
+pub mod views;
+pub mod widgets;
+
+pub use app::{App, ConfirmAction, SessionSort, View};
+pub use theme::{Theme, ThemeName};
+
+use crossterm::event::{self, Event};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use std::time::Duration;
+
+/// TUI を起動し、終了まで実行する。
+pub fn run(mut app: App) -> anyhow::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = std::io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = run_loop(&mut terminal, &mut app);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    result
+}
+
+fn run_loop(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    app: &mut App,
+) -> anyhow::Result<()> {
+    while !app.should_quit {
+        app.update();
+        terminal.draw(|frame| app.render(frame))?;
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                app.handle_key_event(key);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! TUI が表示する状態のスナップショット。
+
+use aad_application::services::LoopState;
+use aad_domain::entities::Session;
+use aad_domain::value_objects::Phase;
+use std::collections::{HashMap, VecDeque};
+
+/// 実行ログのリングバッファ。
+///
+/// LoopEvent や子プロセスの標準出力を蓄積し、上限を超えた古い行は
+/// 切り捨てる。autoscroll が有効なら常に最新行へ追従する。
+#[derive(Debug)]
+pub struct LogBuffer {
+    lines: VecDeque<String>,
+    capacity: usize,
+    /// 最新行への自動追従。
+    pub autoscroll: bool,
+    /// autoscroll 無効時の表示開始行。
+    pub scroll_offset: usize,
+}
+
+impl LogBuffer {
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity,
+            autoscroll: true,
+            scroll_offset: 0,
+        }
+    }
+
+    /// 行を追加する。上限を超えたら最古の行を捨てる。
+    pub fn push(&mut self, line: impl Into<String>) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+            self.scroll_offset = self.scroll_offset.saturating_sub(1);
+        }
+        self.lines.push_back(line.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// 表示領域の高さに合わせて表示する行を返す。
+    pub fn visible_lines(&self, height: usize) -> Vec<&str> {
+        let start = if self.autoscroll {
+            self.lines.len().saturating_sub(height)
+        } else {
+            self.scroll_offset.min(self.lines.len().saturating_sub(1))
+        };
+        self.lines
+            .iter()
+            .skip(start)
+            .take(height)
+            .map(|s| s.as_str())
+            .collect()
+    }
+
+    /// 手動スクロール（autoscroll を解除する）。
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.autoscroll = false;
+        let max = self.lines.len().saturating_sub(1);
+        self.scroll_offset =
+            (self.scroll_offset as isize + delta).clamp(0, max as isize) as usize;
+    }
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_buffer_truncates_at_capacity() {
+        let mut log = LogBuffer::new(3);
+        for i in 0..5 {
+            log.push(format!("line {i}"));
+        }
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.visible_lines(10), vec!["line 2", "line 3", "line 4"]);
+    }
+
+    #[test]
+    fn test_autoscroll_follows_latest_lines() {
+        let mut log = LogBuffer::new(100);
+        for i in 0..10 {
+            log.push(format!("line {i}"));
+        }
+        // 自動追従: 高さ3なら末尾3行
+        assert_eq!(log.visible_lines(3), vec!["line 7", "line 8", "line 9"]);
+
+        // 手動スクロールで自動追従が解除される
+        log.scroll_by(-100);
+        assert!(!log.autoscroll);
+        assert_eq!(log.visible_lines(3), vec!["line 0", "line 1", "line 2"]);
+    }
+}
+
+/// 描画に使う集約済みの状態。`App::update` が更新する。
+#[derive(Debug, Default)]
+pub struct UiState {
+    /// 最後に読み込んだループ状態（ファイルが無ければ `None`）。
+    pub loop_state: Option<LoopState>,
+    /// フェーズごとのセッション数（Orchestrator::phase_distribution 由来）。
+    pub phase_distribution: HashMap<Phase, usize>,
+    /// 表示対象のセッション一覧。
+    pub sessions: Vec<Session>,
+    /// 依存グラフのウェーブ分割（DependencyMiniView が表示）。
+    pub waves: Vec<Vec<String>>,
+    /// タスク実行ログ。
+    pub log: LogBuffer,
+    /// エスカレーション記録の表示行（LogView が表示）。
+    pub escalation_lines: Vec<String>,
+    /// 全タスクの完了率（0.0〜100.0、TaskProgress が表示）。
+    pub task_progress_percent: f64,
+}
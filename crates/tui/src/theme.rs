@@ -0,0 +1,145 @@
+//! 表示テーマ。端末の配色やアクセシビリティ要件に合わせて切り替える。
+
+use ratatui::style::Color;
+use std::str::FromStr;
+
+/// 利用可能なテーマ名。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeName {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeName {
+    /// `t` キーで巡回する次のテーマ。
+    pub fn next(&self) -> ThemeName {
+        match self {
+            ThemeName::Dark => ThemeName::Light,
+            ThemeName::Light => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Dark,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+        }
+    }
+}
+
+impl FromStr for ThemeName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "dark" => Ok(ThemeName::Dark),
+            "light" => Ok(ThemeName::Light),
+            "high-contrast" | "high_contrast" => Ok(ThemeName::HighContrast),
+            other => Err(format!("unknown theme: {other}")),
+        }
+    }
+}
+
+/// 各 UI 要素の配色。全ウィジェットはここから色を引く。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// ボーダーとタイトル。
+    pub border: Color,
+    /// 通常テキスト。
+    pub text: Color,
+    /// 選択行などの強調。
+    pub highlight: Color,
+    /// ゲージ・バーの色。
+    pub accent: Color,
+    /// トーストの背景。
+    pub toast_bg: Color,
+    /// トーストの文字。
+    pub toast_fg: Color,
+}
+
+impl Theme {
+    pub fn by_name(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Dark => Theme::dark(),
+            ThemeName::Light => Theme::light(),
+            ThemeName::HighContrast => Theme::high_contrast(),
+        }
+    }
+
+    pub fn dark() -> Theme {
+        Theme {
+            name: ThemeName::Dark,
+            border: Color::DarkGray,
+            text: Color::Gray,
+            highlight: Color::Cyan,
+            accent: Color::Green,
+            toast_bg: Color::Yellow,
+            toast_fg: Color::Black,
+        }
+    }
+
+    pub fn light() -> Theme {
+        Theme {
+            name: ThemeName::Light,
+            border: Color::Gray,
+            text: Color::Black,
+            highlight: Color::Blue,
+            accent: Color::Green,
+            toast_bg: Color::LightYellow,
+            toast_fg: Color::Black,
+        }
+    }
+
+    pub fn high_contrast() -> Theme {
+        Theme {
+            name: ThemeName::HighContrast,
+            border: Color::White,
+            text: Color::White,
+            highlight: Color::Yellow,
+            accent: Color::White,
+            toast_bg: Color::White,
+            toast_fg: Color::Black,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_name_cycles() {
+        assert_eq!(ThemeName::Dark.next(), ThemeName::Light);
+        assert_eq!(ThemeName::Light.next(), ThemeName::HighContrast);
+        assert_eq!(ThemeName::HighContrast.next(), ThemeName::Dark);
+    }
+
+    #[test]
+    fn test_by_name_changes_colors() {
+        assert_ne!(
+            Theme::by_name(ThemeName::Dark).text,
+            Theme::by_name(ThemeName::Light).text
+        );
+        assert_eq!(Theme::by_name(ThemeName::HighContrast).border, Color::White);
+    }
+
+    #[test]
+    fn test_parse_theme_name() {
+        assert_eq!("dark".parse::<ThemeName>().unwrap(), ThemeName::Dark);
+        assert_eq!(
+            "high-contrast".parse::<ThemeName>().unwrap(),
+            ThemeName::HighContrast
+        );
+        assert!("neon".parse::<ThemeName>().is_err());
+    }
+}
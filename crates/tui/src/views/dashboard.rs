@@ -0,0 +1,38 @@
+use crate::app::App;
+use crate::widgets::{DependencyMiniView, SessionList, TaskProgress};
+use aad_domain::value_objects::SessionStatus;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::Frame;
+use std::collections::HashMap;
+
+/// ダッシュボード: セッション一覧・依存グラフのミニビュー・全体進捗。
+pub fn render(app: &App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(6),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+
+    let sessions = if app.state.sessions.is_empty() {
+        vec!["No active sessions".to_string()]
+    } else {
+        app.sorted_sessions()
+            .iter()
+            .map(|s| format!("{} — {} [{}] {}", s.id, s.spec_id, s.phase, s.status))
+            .collect()
+    };
+    SessionList::new(sessions, app.selected_index).render(frame, chunks[0], &app.theme);
+
+    let statuses: HashMap<String, SessionStatus> = app
+        .state
+        .sessions
+        .iter()
+        .map(|s| (s.spec_id.to_string(), s.status))
+        .collect();
+    DependencyMiniView::new(&app.state.waves, &statuses).render(frame, chunks[1], &app.theme);
+
+    TaskProgress::new(app.state.task_progress_percent).render(frame, chunks[2], &app.theme);
+}
@@ -0,0 +1,24 @@
+use crate::app::App;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// 詳細: 選択中セッションの情報。
+pub fn render(app: &App, frame: &mut Frame) {
+    let text = match &app.state.loop_state {
+        Some(state) => format!(
+            "Spec: {}\n実行中タスク: {}\n一時停止: {}\n更新: {}",
+            state.spec_id,
+            state
+                .current_task
+                .as_ref()
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "なし".to_string()),
+            if state.paused { "はい" } else { "いいえ" },
+            state.updated_at.to_rfc3339(),
+        ),
+        None => "ループ状態ファイルが見つかりません".to_string(),
+    };
+    let paragraph =
+        Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("詳細"));
+    frame.render_widget(paragraph, frame.area());
+}
@@ -0,0 +1,75 @@
+use crate::app::App;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// ログビュー: エスカレーション記録と MonitorEvent/LoopEvent を時系列表示する。
+pub fn render(app: &App, frame: &mut Frame) {
+    let area = frame.area();
+    let height = area.height.saturating_sub(2) as usize;
+    let paragraph = Paragraph::new(lines(app, height)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.border))
+            .title("ログ (エスカレーション + イベント)"),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// 表示する行を組み立てる。レベルで色分けし、最新が下に来る。
+pub(crate) fn lines(app: &App, height: usize) -> Vec<Line<'static>> {
+    let mut all: Vec<Line<'static>> = Vec::new();
+    for line in &app.state.escalation_lines {
+        all.push(colored_line(line, &app.theme));
+    }
+    for line in app.state.log.visible_lines(height) {
+        all.push(colored_line(line, &app.theme));
+    }
+    if all.is_empty() {
+        return vec![Line::from("ログがありません")];
+    }
+    // 最新が下に来るよう末尾から height 行だけ残す
+    let skip = all.len().saturating_sub(height.max(1));
+    all.split_off(skip)
+}
+
+fn colored_line(text: &str, theme: &crate::theme::Theme) -> Line<'static> {
+    let lower = text.to_ascii_lowercase();
+    let style = if lower.contains("critical") {
+        Style::default().fg(Color::Red)
+    } else if lower.contains("error") || lower.contains("failed") {
+        Style::default().fg(Color::LightRed)
+    } else if lower.contains("warning") {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    Line::styled(text.to_string(), style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_logs_show_placeholder() {
+        let app = App::new();
+        let lines = lines(&app, 10);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans[0].content, "ログがありません");
+    }
+
+    #[test]
+    fn test_lines_include_escalations_and_events() {
+        let mut app = App::new();
+        app.state
+            .escalation_lines
+            .push("[critical] 20260901-sess-01".to_string());
+        app.append_log("[warning] retry attempt 1");
+
+        let lines = lines(&app, 10);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].spans[0].content, "[warning] retry attempt 1");
+    }
+}
@@ -0,0 +1,7 @@
+//! ビュー — 画面全体のレイアウトと描画。
+
+pub mod dashboard;
+pub mod detail;
+pub mod logs;
+pub mod monitor;
+pub mod workflow;
@@ -0,0 +1,53 @@
+use crate::app::App;
+use crate::widgets::{ContextBar, SessionList};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// モニター: 実行中セッション・実行ログ・コンテキスト使用率。
+pub fn render(app: &App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(8),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+
+    let sessions = if app.state.sessions.is_empty() {
+        vec!["No active sessions".to_string()]
+    } else {
+        app.sorted_sessions()
+            .iter()
+            .map(|s| format!("{} — {}", s.id, s.status))
+            .collect()
+    };
+    SessionList::new(sessions, app.selected_index).render(frame, chunks[0], &app.theme);
+
+    // 実行ログのペイン（PageUp/PageDown でスクロール、a で自動追従トグル）
+    let log_height = chunks[1].height.saturating_sub(2) as usize;
+    let log_text = app.state.log.visible_lines(log_height).join("\n");
+    let title = if app.state.log.autoscroll {
+        "ログ (自動追従)"
+    } else {
+        "ログ"
+    };
+    let log_pane = Paragraph::new(log_text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.border))
+            .title(title),
+    );
+    frame.render_widget(log_pane, chunks[1]);
+
+    // コンテキスト使用率は実セッションの最大値を表示する
+    let usage = app
+        .state
+        .sessions
+        .iter()
+        .map(|s| s.context_usage)
+        .fold(0.0, f64::max);
+    ContextBar::new(usage).render(frame, chunks[2], &app.theme);
+}
@@ -0,0 +1,61 @@
+use crate::app::App;
+use crate::widgets::PhaseIndicator;
+use aad_domain::value_objects::Phase;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// ワークフロー: フェーズ進行の俯瞰とフェーズ別セッション分布。
+pub fn render(app: &App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Min(3),
+        ])
+        .split(frame.area());
+
+    PhaseIndicator::new(Phase::Tdd).render(frame, chunks[0], &app.theme);
+
+    let bars = Paragraph::new(distribution_lines(app))
+        .block(Block::default().borders(Borders::ALL).title("フェーズ分布"));
+    frame.render_widget(bars, chunks[1]);
+
+    let help = Paragraph::new("Tab: ビュー切替 / Enter: 詳細 / q: 終了")
+        .block(Block::default().borders(Borders::ALL).title("操作"));
+    frame.render_widget(help, chunks[2]);
+}
+
+/// フェーズごとの件数を `TDD    ██ 2` のようなバー行に整形する。
+pub(crate) fn distribution_lines(app: &App) -> String {
+    Phase::all()
+        .iter()
+        .map(|phase| {
+            let count = app
+                .state
+                .phase_distribution
+                .get(phase)
+                .copied()
+                .unwrap_or(0);
+            format!("{:<6} {} {}\n", phase.as_str(), "█".repeat(count), count)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_lines_render_counts() {
+        let mut app = App::new();
+        app.state.phase_distribution.insert(Phase::Tdd, 2);
+        app.state.phase_distribution.insert(Phase::Review, 1);
+
+        let lines = distribution_lines(&app);
+        assert!(lines.contains("TDD    ██ 2"));
+        assert!(lines.contains("REVIEW █ 1"));
+        assert!(lines.contains("SPEC    0"));
+    }
+}
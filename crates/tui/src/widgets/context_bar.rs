@@ -0,0 +1,57 @@
+use crate::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Borders, Gauge};
+use ratatui::Frame;
+
+/// コンテキスト使用率バー。70% ルールの警告色で表示する。
+pub struct ContextBar {
+    /// 使用率（0.0〜100.0）。
+    usage: f64,
+}
+
+impl ContextBar {
+    pub fn new(usage: f64) -> Self {
+        Self {
+            usage: usage.clamp(0.0, 100.0),
+        }
+    }
+
+    /// CLAUDE.md の 70% ルールに対応した警告色。
+    pub fn color(&self) -> Color {
+        match self.usage {
+            u if u >= 95.0 => Color::Magenta,
+            u if u >= 85.0 => Color::Red,
+            u if u >= 70.0 => Color::LightRed,
+            u if u >= 50.0 => Color::Yellow,
+            _ => Color::Green,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border))
+                    .title("コンテキスト使用率"),
+            )
+            .gauge_style(Style::default().fg(self.color()))
+            .percent(self.usage as u16);
+        frame.render_widget(gauge, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warning_colors_follow_70_percent_rule() {
+        assert_eq!(ContextBar::new(30.0).color(), Color::Green);
+        assert_eq!(ContextBar::new(60.0).color(), Color::Yellow);
+        assert_eq!(ContextBar::new(75.0).color(), Color::LightRed);
+        assert_eq!(ContextBar::new(90.0).color(), Color::Red);
+        assert_eq!(ContextBar::new(99.0).color(), Color::Magenta);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::theme::Theme;
+use aad_domain::value_objects::SessionStatus;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+use std::collections::HashMap;
+
+/// 依存グラフのウェーブ構造を俯瞰する簡易ウィジェット。
+///
+/// 各ウェーブを縦に並べ、Spec をブロック表示する。完了済みは暗く、
+/// 実行中は強調される。
+pub struct DependencyMiniView<'a> {
+    waves: &'a [Vec<String>],
+    /// spec_id → セッション状態。
+    statuses: &'a HashMap<String, SessionStatus>,
+}
+
+impl<'a> DependencyMiniView<'a> {
+    pub fn new(
+        waves: &'a [Vec<String>],
+        statuses: &'a HashMap<String, SessionStatus>,
+    ) -> Self {
+        Self { waves, statuses }
+    }
+
+    /// 描画する行を組み立てる。テストから構造を検証できるよう分離。
+    pub(crate) fn lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        if self.waves.is_empty() {
+            return vec![Line::from("依存グラフがありません")];
+        }
+        self.waves
+            .iter()
+            .enumerate()
+            .map(|(i, wave)| {
+                let mut spans = vec![Span::styled(
+                    format!("W{} ", i + 1),
+                    Style::default().fg(theme.text),
+                )];
+                for spec in wave {
+                    let style = match self.statuses.get(spec) {
+                        Some(SessionStatus::Completed) => {
+                            Style::default().fg(theme.border).add_modifier(Modifier::DIM)
+                        }
+                        Some(SessionStatus::Running) => Style::default()
+                            .fg(theme.highlight)
+                            .add_modifier(Modifier::BOLD),
+                        Some(SessionStatus::Failed | SessionStatus::Escalated) => {
+                            Style::default().fg(ratatui::style::Color::Red)
+                        }
+                        _ => Style::default().fg(theme.text),
+                    };
+                    spans.push(Span::styled(format!("[{spec}] "), style));
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let paragraph = Paragraph::new(self.lines(theme)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border))
+                .title("依存グラフ"),
+        );
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_reflect_wave_structure() {
+        let waves = vec![
+            vec!["SPEC-001".to_string()],
+            vec!["SPEC-002".to_string(), "SPEC-003".to_string()],
+        ];
+        let mut statuses = HashMap::new();
+        statuses.insert("SPEC-001".to_string(), SessionStatus::Completed);
+        statuses.insert("SPEC-002".to_string(), SessionStatus::Running);
+
+        let view = DependencyMiniView::new(&waves, &statuses);
+        let lines = view.lines(&Theme::default());
+        assert_eq!(lines.len(), 2);
+
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.clone()).collect())
+            .collect();
+        assert!(rendered[0].starts_with("W1"));
+        assert!(rendered[0].contains("[SPEC-001]"));
+        assert!(rendered[1].contains("[SPEC-002]"));
+        assert!(rendered[1].contains("[SPEC-003]"));
+    }
+
+    #[test]
+    fn test_empty_graph_placeholder() {
+        let waves: Vec<Vec<String>> = Vec::new();
+        let statuses = HashMap::new();
+        let view = DependencyMiniView::new(&waves, &statuses);
+        let lines = view.lines(&Theme::default());
+        assert_eq!(lines.len(), 1);
+    }
+}
@@ -0,0 +1,13 @@
+//! 再利用可能なウィジェット。
+
+pub mod context_bar;
+pub mod dependency_mini_view;
+pub mod phase_indicator;
+pub mod session_list;
+pub mod task_progress;
+
+pub use context_bar::ContextBar;
+pub use dependency_mini_view::DependencyMiniView;
+pub use phase_indicator::PhaseIndicator;
+pub use session_list::SessionList;
+pub use task_progress::TaskProgress;
@@ -0,0 +1,42 @@
+use crate::theme::Theme;
+use aad_domain::value_objects::Phase;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// 6フェーズの進行位置を示すインジケーター。
+pub struct PhaseIndicator {
+    current: Phase,
+}
+
+impl PhaseIndicator {
+    pub fn new(current: Phase) -> Self {
+        Self { current }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut spans = Vec::new();
+        for (i, phase) in Phase::all().iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(" → "));
+            }
+            let style = if *phase == self.current {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            spans.push(Span::styled(phase.as_str(), style));
+        }
+        let paragraph = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border))
+                .title("フェーズ"),
+        );
+        frame.render_widget(paragraph, area);
+    }
+}
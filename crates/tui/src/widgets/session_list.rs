@@ -0,0 +1,62 @@
+use crate::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{
+    Block, Borders, List, ListItem, ListState, Scrollbar, ScrollbarOrientation,
+    ScrollbarState,
+};
+use ratatui::Frame;
+
+/// セッション一覧のリストウィジェット。
+///
+/// 表示領域を超える場合は選択が見えるよう自動スクロールし、
+/// スクロールバーを表示する。
+pub struct SessionList {
+    items: Vec<String>,
+    selected: usize,
+}
+
+impl SessionList {
+    pub fn new(items: Vec<String>, selected: usize) -> Self {
+        Self { items, selected }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let total = self.items.len();
+        let selected = self.selected.min(total.saturating_sub(1));
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|item| ListItem::new(item.clone()).style(Style::default().fg(theme.text)))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border))
+                    .title("セッション"),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::REVERSED),
+            );
+
+        // 選択が画面外へ出たら ListState が自動でスクロールする
+        let mut state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(list, area, &mut state);
+
+        let visible = area.height.saturating_sub(2) as usize;
+        if total > visible {
+            let mut scrollbar_state =
+                ScrollbarState::new(total.saturating_sub(visible)).position(state.offset());
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area,
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, Gauge};
+use ratatui::Frame;
+
+/// タスク進捗のゲージウィジェット。
+pub struct TaskProgress {
+    /// 完了率（0.0〜100.0）。
+    percent: f64,
+}
+
+impl TaskProgress {
+    pub fn new(percent: f64) -> Self {
+        Self {
+            percent: percent.clamp(0.0, 100.0),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border))
+                    .title("進捗"),
+            )
+            .gauge_style(Style::default().fg(theme.accent))
+            .percent(self.percent as u16);
+        frame.render_widget(gauge, area);
+    }
+}